@@ -1,4 +1,4 @@
-use crate::models::{PutioFile, PutioTransferResponse};
+use crate::models::{PutioFile, PutioTransfer, PutioTransferResponse};
 use serde::Deserialize;
 use std::error::Error;
 
@@ -13,6 +13,11 @@ struct FilesResponse {
     files: Vec<PutioFile>,
 }
 
+#[derive(Deserialize)]
+struct TransfersResponse {
+    transfers: Vec<PutioTransfer>,
+}
+
 #[derive(Deserialize)]
 struct AccountInfo {
     info: AccountData,
@@ -40,25 +45,20 @@ impl PutioClient {
         Ok(account.info.username)
     }
 
-    pub fn find_or_create_folder(&self, folder_name: &str) -> Result<u64, Box<dyn Error>> {
-        // List files in root (parent_id = 0)
-        let response = ureq::get(&format!("{}/files/list?parent_id=0", self.base_url))
+    /// List the folders directly inside `parent_id` (0 is the account root)
+    pub fn list_folders(&self, parent_id: u64) -> Result<Vec<PutioFile>, Box<dyn Error>> {
+        let response = ureq::get(&format!("{}/files/list?parent_id={}", self.base_url, parent_id))
             .set("Authorization", &format!("Bearer {}", self.token))
             .call()?;
 
         let files_response: FilesResponse = serde_json::from_reader(response.into_reader())?;
+        Ok(files_response.files.into_iter().filter(|f| f.is_folder()).collect())
+    }
 
-        // Check if folder exists
-        for file in &files_response.files {
-            if file.name == folder_name {
-                return Ok(file.id);
-            }
-        }
-
-        // Create folder if it doesn't exist
+    pub fn create_folder(&self, folder_name: &str, parent_id: u64) -> Result<PutioFile, Box<dyn Error>> {
         let response = ureq::post(&format!("{}/files/create-folder", self.base_url))
             .set("Authorization", &format!("Bearer {}", self.token))
-            .send_form(&[("name", folder_name), ("parent_id", "0")])?;
+            .send_form(&[("name", folder_name), ("parent_id", &parent_id.to_string())])?;
 
         #[derive(Deserialize)]
         struct CreateFolderResponse {
@@ -66,10 +66,53 @@ impl PutioClient {
         }
 
         let create_response: CreateFolderResponse = serde_json::from_reader(response.into_reader())?;
-        Ok(create_response.file.id)
+        Ok(create_response.file)
+    }
+
+    /// Resolve a slash-separated folder path from the root, creating any
+    /// missing folders along the way
+    pub fn find_or_create_folder_path(&self, path: &str) -> Result<u64, Box<dyn Error>> {
+        let mut parent_id = 0;
+
+        for name in path.split('/').filter(|part| !part.is_empty()) {
+            let existing = self.list_folders(parent_id)?
+                .into_iter()
+                .find(|folder| folder.name == name);
+
+            parent_id = match existing {
+                Some(folder) => folder.id,
+                None => self.create_folder(name, parent_id)?.id,
+            };
+        }
+
+        Ok(parent_id)
+    }
+
+    /// Resolve a slash-separated folder path from the root without creating anything
+    pub fn find_folder_path(&self, path: &str) -> Result<u64, Box<dyn Error>> {
+        let mut parent_id = 0;
+
+        for name in path.split('/').filter(|part| !part.is_empty()) {
+            parent_id = self.list_folders(parent_id)?
+                .into_iter()
+                .find(|folder| folder.name == name)
+                .map(|folder| folder.id)
+                .ok_or_else(|| format!("Folder not found: {}", path))?;
+        }
+
+        Ok(parent_id)
+    }
+
+    pub fn list_transfers(&self) -> Result<Vec<PutioTransfer>, Box<dyn Error>> {
+        let response = ureq::get(&format!("{}/transfers/list", self.base_url))
+            .set("Authorization", &format!("Bearer {}", self.token))
+            .call()?;
+
+        let transfers_response: TransfersResponse = serde_json::from_reader(response.into_reader())?;
+        Ok(transfers_response.transfers)
     }
 
-    pub fn add_transfer(&self, magnet: &str, parent_id: u64) -> Result<u64, Box<dyn Error>> {
+    pub fn add_transfer(&self, magnet: &str, parent_id: u64) -> Result<PutioTransfer, Box<dyn Error>> {
         let response = ureq::post(&format!("{}/transfers/add", self.base_url))
             .set("Authorization", &format!("Bearer {}", self.token))
             .send_form(&[
@@ -78,10 +121,11 @@ impl PutioClient {
             ])?;
 
         let transfer_response: PutioTransferResponse = serde_json::from_reader(response.into_reader())?;
-        Ok(transfer_response.transfer.id)
+        Ok(transfer_response.transfer)
     }
 
     /// Initiate OAuth flow - returns authorization URL
+    #[allow(dead_code)]
     pub fn get_oauth_url(client_id: &str) -> String {
         format!(
             "https://app.put.io/v2/oauth2/authenticate?client_id={}&response_type=code&redirect_uri=urn:ietf:wg:oauth:2.0:oob",
@@ -90,6 +134,7 @@ impl PutioClient {
     }
 
     /// Exchange OAuth code for access token
+    #[allow(dead_code)]
     pub fn exchange_code(
         client_id: &str,
         client_secret: &str,
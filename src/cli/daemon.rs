@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::{chill_client, putio_client, target_folder, CliResult, EXIT_API, EXIT_OK};
+use crate::api::{ChillClient, PutioClient};
+use crate::config::{Config, DaemonState, SavedSearches, SentLog, Watch};
+use crate::models::{looks_unsafe, merge_duplicates, Filters, IndexerRegistry, ParsedQuery};
+
+pub struct DaemonArgs {
+    /// Run every watch once and exit, for cron or systemd timers
+    pub once: bool,
+    /// Log what would be sent without sending it
+    pub dry_run: bool,
+    /// Minutes between runs, overriding the config
+    pub interval: Option<u64>,
+}
+
+/// Run the watched saved searches on a schedule, sending the first
+/// acceptable new result of each to Put.io. Logs go to stdout (sends) and
+/// stderr (errors) without colors, so the journal keeps them readable.
+pub fn daemon(args: &DaemonArgs, config: &Config, registry: &Arc<IndexerRegistry>, debug: bool) -> CliResult {
+    // Fail fast on a missing setup rather than logging it every cycle
+    chill_client(config)?;
+    if !args.dry_run {
+        putio_client(config)?;
+    }
+
+    let mut config = config.clone();
+    let mut registry = Arc::clone(registry);
+    loop {
+        let failed = run_watches(&config, &registry, args.dry_run, debug);
+
+        if args.once {
+            return Ok(if failed > 0 { EXIT_API } else { EXIT_OK });
+        }
+
+        let minutes = args.interval.unwrap_or(config.daemon.interval_minutes).max(1);
+        if debug {
+            eprintln!("[DEBUG] Next run in {} minutes", minutes);
+        }
+        thread::sleep(Duration::from_secs(minutes * 60));
+
+        // Pick up edits to the watches and rules without a restart
+        let reloaded = Config::load().and_then(|reloaded| {
+            let indexers = IndexerRegistry::from_entries(&reloaded.indexers)?;
+            Ok((reloaded, indexers))
+        });
+        match reloaded {
+            Ok((reloaded, indexers)) => {
+                config = reloaded;
+                registry = Arc::new(indexers);
+            }
+            Err(e) => eprintln!("✗ Could not reload config, keeping the previous one: {}", e),
+        }
+    }
+}
+
+/// Run every watch once and return how many failed
+fn run_watches(config: &Config, registry: &Arc<IndexerRegistry>, dry_run: bool, debug: bool) -> usize {
+    let watches = &config.daemon.watch;
+    if watches.is_empty() {
+        eprintln!("No watches configured. Add saved searches to the \"daemon\" section of the config.");
+        return 0;
+    }
+
+    // A dry run has no Put.io client, which is what keeps it from sending
+    let clients = chill_client(config).and_then(|chill| {
+        let putio = if dry_run { None } else { Some(putio_client(config)?) };
+        Ok((chill, putio))
+    });
+    let (chill, putio) = match clients {
+        Ok(clients) => clients,
+        Err(e) => {
+            eprintln!("✗ {}", e.message);
+            return watches.len();
+        }
+    };
+
+    let saved = SavedSearches::load();
+    let mut state = DaemonState::load();
+    let mut sent_log = SentLog::load();
+    let mut failed = 0;
+
+    for watch in watches {
+        if let Err(e) = run_watch(watch, &saved, &mut state, &mut sent_log, &chill, putio.as_ref(), config, registry, debug) {
+            eprintln!("✗ [{}] {}", watch.search, e);
+            failed += 1;
+        }
+    }
+
+    failed
+}
+
+/// Run one watch. The first run only records what is already out; later
+/// runs send the best result (by the watch's quality profile, or else the
+/// saved search's sort) that passes the rules, doesn't look fake, wasn't
+/// already passing on the previous run and was never sent before. Results
+/// that fail the rules aren't recorded, so they are looked at again once
+/// they pass, e.g. after gaining seeders. State is only saved once the
+/// send went through, so a failed send is retried, and dry runs leave it
+/// untouched.
+#[allow(clippy::too_many_arguments)]
+fn run_watch(
+    watch: &Watch,
+    saved: &SavedSearches,
+    state: &mut DaemonState,
+    sent_log: &mut SentLog,
+    chill: &ChillClient,
+    putio: Option<&PutioClient>,
+    config: &Config,
+    registry: &Arc<IndexerRegistry>,
+    debug: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let name = &watch.search;
+    let search = saved.get(name).ok_or_else(|| format!("no saved search named '{}'", name))?;
+
+    let parsed = ParsedQuery::parse(&search.query);
+    let mut filters = Filters { registry: Arc::clone(registry), ..Filters::default() };
+    search.filters.restore(&mut filters);
+    let mut filters = parsed.apply_to(&filters, parsed.indexers.clone());
+    config.blocklist.apply_to(&mut filters);
+    watch.rules.apply_to(&mut filters)?;
+
+    let indexers = registry.api_ids(&filters.indexers)?;
+
+    let profile = match &watch.profile {
+        Some(profile) => Some(
+            config.profiles.iter()
+                .find(|p| p.name == *profile)
+                .ok_or_else(|| format!("no quality profile named '{}'", profile))?,
+        ),
+        None => None,
+    };
+
+    if debug {
+        eprintln!("[DEBUG] [{}] Searching '{}' on {:?}", name, parsed.keyword, indexers);
+    }
+
+    let mut results = merge_duplicates(chill.search(&parsed.keyword, Some(&indexers), filters.filter_nsfw)?);
+    filters.apply(&mut results);
+    results.retain(|result| {
+        watch.rules.accepts_quality(result)
+            && !looks_unsafe(result)
+            && profile.is_none_or(|profile| profile.score(result).is_some())
+    });
+    let hashes: Vec<String> = results.iter().filter_map(|r| r.infohash()).collect();
+
+    let Some(previous) = state.get(name) else {
+        println!("[{}] First run, {} results recorded; sending starts with the next new one", name, hashes.len());
+        return record_run(state, name, hashes, putio.is_some());
+    };
+
+    let seen: HashSet<&String> = previous.seen.iter().collect();
+    let mut candidates = results.iter().filter(|result| {
+        result.infohash().is_some_and(|hash| !seen.contains(&hash) && sent_log.get(&hash).is_none())
+    });
+
+    // With a profile, the best scored candidate; the first one wins ties
+    let accepted = match profile {
+        Some(profile) => candidates
+            .filter_map(|result| profile.score(result).map(|score| (score, result)))
+            .fold(None, |best: Option<(u32, _)>, (score, result)| match best {
+                Some((best_score, _)) if best_score >= score => best,
+                _ => Some((score, result)),
+            })
+            .map(|(_, result)| result),
+        None => candidates.next(),
+    };
+
+    match (accepted, putio) {
+        (None, _) => {
+            if debug {
+                eprintln!("[DEBUG] [{}] Nothing new to send", name);
+            }
+        }
+        (Some(result), None) => println!("[{}] Would send '{}'", name, result.title),
+        (Some(result), Some(putio)) => {
+            let folder_id = target_folder(putio, watch.folder.as_deref(), config)?;
+            let transfer = putio.add_transfer(&result.magnet, folder_id)?;
+            println!("[{}] Sent '{}' ({})", name, result.title, transfer.id);
+
+            if let Some(hash) = result.infohash() {
+                sent_log.record(&hash, &result.title);
+                sent_log.save()?;
+            }
+        }
+    }
+
+    record_run(state, name, hashes, putio.is_some())
+}
+
+/// Remember what a run returned, unless it was a dry run
+fn record_run(state: &mut DaemonState, name: &str, hashes: Vec<String>, sending: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if !sending {
+        return Ok(());
+    }
+    state.record(name, hashes);
+    state.save()
+}
@@ -0,0 +1,479 @@
+mod daemon;
+mod output;
+
+use std::io::{self, BufRead};
+use std::sync::Arc;
+
+use crate::api::{ChillClient, PutioClient};
+use crate::config::{Config, SearchCache, SentLog};
+use crate::models::{merge_duplicates, Filters, IndexerRegistry, Magnet, SortMode, SortOrder, TorrentResult};
+use crate::ui::App;
+
+pub use daemon::DaemonArgs;
+pub use output::OutputFormat;
+
+// Process exit codes for scripted use
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_NO_RESULTS: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_CONFIG: i32 = 3;
+pub const EXIT_API: i32 = 4;
+/// Picker closed without choosing anything (same as fzf)
+pub const EXIT_CANCELLED: i32 = 130;
+
+/// Flags understood by the interactive app that may appear anywhere
+const GLOBAL_FLAGS: [&str; 4] = ["--debug", "--logging", "--setup", "--offline"];
+
+pub enum Command {
+    Search(SearchArgs),
+    Pick(SearchArgs),
+    Send(SendArgs),
+    Transfers,
+    Folders { path: Option<String> },
+    Indexers,
+    Daemon(DaemonArgs),
+}
+
+pub struct SearchArgs {
+    pub query: String,
+    pub indexers: Vec<String>,
+    pub min_seeds: u32,
+    pub sort: SortOrder,
+    pub allow_nsfw: bool,
+    pub hide_suspicious: bool,
+    pub limit: Option<usize>,
+    pub format: OutputFormat,
+}
+
+pub struct SendArgs {
+    pub magnets: Vec<String>,
+    pub folder: Option<String>,
+}
+
+struct CliError {
+    code: i32,
+    message: String,
+}
+
+impl CliError {
+    fn config(message: &str) -> Self {
+        Self { code: EXIT_CONFIG, message: message.to_string() }
+    }
+
+    fn usage(message: String) -> Self {
+        Self { code: EXIT_USAGE, message }
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for CliError {
+    fn from(e: Box<dyn std::error::Error>) -> Self {
+        Self { code: EXIT_API, message: e.to_string() }
+    }
+}
+
+type CliResult = Result<i32, CliError>;
+
+/// Cursor over the arguments of a subcommand
+struct ArgParser {
+    args: std::vec::IntoIter<String>,
+}
+
+impl ArgParser {
+    fn next(&mut self) -> Option<String> {
+        self.args.next()
+    }
+
+    /// Value for `flag`, given either as `--flag value` or `--flag=value`
+    fn value(&mut self, flag: &str, inline: Option<String>) -> Result<String, String> {
+        inline
+            .or_else(|| self.args.next())
+            .ok_or_else(|| format!("{} requires a value", flag))
+    }
+}
+
+/// Split `--flag=value` into its parts
+fn split_flag(arg: &str) -> (String, Option<String>) {
+    match arg.split_once('=') {
+        Some((flag, value)) if arg.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+        _ => (arg.to_string(), None),
+    }
+}
+
+impl Command {
+    /// Parse the process arguments (without the program name).
+    ///
+    /// Returns `Ok(None)` when no subcommand was given and the interactive
+    /// app should start.
+    pub fn parse(args: &[String]) -> Result<Option<Command>, String> {
+        let mut args = args.iter()
+            .filter(|a| !GLOBAL_FLAGS.contains(&a.as_str()))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let Some(name) = args.next() else {
+            return Ok(None);
+        };
+        if name.starts_with('-') {
+            return Ok(None);
+        }
+
+        let mut parser = ArgParser { args };
+        let command = match name.as_str() {
+            "search" => Command::Search(Self::parse_search(&mut parser, OutputFormat::Table)?),
+            "pick" => Command::Pick(Self::parse_search(&mut parser, OutputFormat::Magnets)?),
+            "send" => Command::Send(Self::parse_send(&mut parser)?),
+            "transfers" => {
+                if let Some(extra) = parser.next() {
+                    return Err(format!("unexpected argument '{}'", extra));
+                }
+                Command::Transfers
+            }
+            "folders" => {
+                let path = parser.next();
+                if let Some(extra) = parser.next() {
+                    return Err(format!("unexpected argument '{}'", extra));
+                }
+                Command::Folders { path }
+            }
+            "indexers" => {
+                if let Some(extra) = parser.next() {
+                    return Err(format!("unexpected argument '{}'", extra));
+                }
+                Command::Indexers
+            }
+            "daemon" => Command::Daemon(Self::parse_daemon(&mut parser)?),
+            _ => return Err(format!("unknown command '{}'", name)),
+        };
+
+        Ok(Some(command))
+    }
+
+    fn parse_search(parser: &mut ArgParser, format: OutputFormat) -> Result<SearchArgs, String> {
+        let mut words = Vec::new();
+        let mut search = SearchArgs {
+            query: String::new(),
+            indexers: Vec::new(),
+            min_seeds: 0,
+            sort: SortOrder::default(),
+            allow_nsfw: false,
+            hide_suspicious: false,
+            limit: None,
+            format,
+        };
+
+        while let Some(arg) = parser.next() {
+            let (flag, inline) = split_flag(&arg);
+            match flag.as_str() {
+                "--indexer" | "-i" => {
+                    let value = parser.value(&flag, inline)?;
+                    search.indexers.extend(value.split(',').filter(|s| !s.is_empty()).map(str::to_string));
+                }
+                "--min-seeds" => {
+                    let value = parser.value(&flag, inline)?;
+                    search.min_seeds = value.parse().map_err(|_| format!("invalid --min-seeds '{}'", value))?;
+                }
+                "--sort" => {
+                    let then_by = search.sort.then_by;
+                    search.sort = parser.value(&flag, inline)?.parse()?;
+                    search.sort.then_by = then_by;
+                }
+                "--then" => {
+                    search.sort.then_by = Some(parser.value(&flag, inline)?.parse::<SortMode>()?);
+                }
+                "--limit" | "-n" => {
+                    let value = parser.value(&flag, inline)?;
+                    search.limit = Some(value.parse().map_err(|_| format!("invalid --limit '{}'", value))?);
+                }
+                "--format" | "-o" => {
+                    search.format = parser.value(&flag, inline)?.parse()?;
+                }
+                "--nsfw" => search.allow_nsfw = true,
+                "--hide-suspicious" => search.hide_suspicious = true,
+                "--" => words.extend(parser.args.by_ref()),
+                _ if flag.starts_with('-') && flag.len() > 1 => return Err(format!("unknown option '{}'", flag)),
+                _ => words.push(arg),
+            }
+        }
+
+        search.query = words.join(" ");
+        if search.query.trim().is_empty() {
+            return Err("a QUERY is required".to_string());
+        }
+        Ok(search)
+    }
+
+    fn parse_daemon(parser: &mut ArgParser) -> Result<DaemonArgs, String> {
+        let mut daemon = DaemonArgs { once: false, dry_run: false, interval: None };
+
+        while let Some(arg) = parser.next() {
+            let (flag, inline) = split_flag(&arg);
+            match flag.as_str() {
+                "--once" => daemon.once = true,
+                "--dry-run" => daemon.dry_run = true,
+                "--interval" => {
+                    let value = parser.value(&flag, inline)?;
+                    let minutes: u64 = value.parse().map_err(|_| format!("invalid --interval '{}'", value))?;
+                    if minutes == 0 {
+                        return Err("--interval must be at least 1 minute".to_string());
+                    }
+                    daemon.interval = Some(minutes);
+                }
+                _ => return Err(format!("unexpected argument '{}'", arg)),
+            }
+        }
+
+        Ok(daemon)
+    }
+
+    fn parse_send(parser: &mut ArgParser) -> Result<SendArgs, String> {
+        let mut send = SendArgs { magnets: Vec::new(), folder: None };
+
+        while let Some(arg) = parser.next() {
+            let (flag, inline) = split_flag(&arg);
+            match flag.as_str() {
+                "--folder" | "-f" => send.folder = Some(parser.value(&flag, inline)?),
+                "-" => {
+                    // Read magnets from stdin, one per line
+                    for line in io::stdin().lock().lines() {
+                        let line = line.map_err(|e| e.to_string())?;
+                        let line = line.trim();
+                        if !line.is_empty() {
+                            send.magnets.push(line.to_string());
+                        }
+                    }
+                }
+                _ if flag.starts_with('-') => return Err(format!("unknown option '{}'", flag)),
+                _ => send.magnets.push(arg),
+            }
+        }
+
+        if send.magnets.is_empty() {
+            return Err("send requires at least one MAGNET (or '-' to read from stdin)".to_string());
+        }
+        Ok(send)
+    }
+}
+
+/// Run a subcommand and return the process exit code
+pub fn run(command: Command, config: &Config, registry: &Arc<IndexerRegistry>, debug: bool) -> i32 {
+    let result = match command {
+        Command::Search(args) => search(&args, config, registry, debug),
+        Command::Pick(args) => pick(&args, config, registry, debug),
+        Command::Send(args) => send(&args, config, debug),
+        Command::Transfers => transfers(config),
+        Command::Folders { path } => folders(path.as_deref(), config),
+        Command::Indexers => indexers(registry),
+        Command::Daemon(args) => daemon::daemon(&args, config, registry, debug),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("✗ {}", e.message);
+            e.code
+        }
+    }
+}
+
+fn chill_client(config: &Config) -> Result<ChillClient, CliError> {
+    config.chill_api_key
+        .as_ref()
+        .map(|key| ChillClient::new(key.clone(), config.putio_oauth_token.clone()))
+        .ok_or_else(|| CliError::config("Chill API key not configured. Run 'chilltui --setup'."))
+}
+
+fn putio_client(config: &Config) -> Result<PutioClient, CliError> {
+    config.putio_oauth_token
+        .as_ref()
+        .map(|token| PutioClient::new(token.clone()))
+        .ok_or_else(|| CliError::config("Put.io not configured. Run 'chilltui --setup'."))
+}
+
+/// Run a search the same way the interactive app does. A cached response
+/// younger than the TTL is used instead of the API; offline, any is.
+fn fetch_results(client: &ChillClient, args: &SearchArgs, indexers: &[String], config: &Config, registry: &Arc<IndexerRegistry>, debug: bool) -> Result<Vec<TorrentResult>, Box<dyn std::error::Error>> {
+    if debug {
+        eprintln!("[DEBUG] CLI search for '{}' on {:?}", args.query, indexers);
+    }
+
+    let mut filters = Filters {
+        sort: args.sort,
+        min_seeds: args.min_seeds,
+        hide_suspicious: args.hide_suspicious,
+        registry: Arc::clone(registry),
+        ..Filters::default()
+    };
+    config.blocklist.apply_to(&mut filters);
+
+    let filter_nsfw = !args.allow_nsfw;
+    let cached = SearchCache::get(&args.query, indexers, filter_nsfw).filter(|cached| config.cache.is_fresh(cached.age()));
+    let raw = match cached {
+        Some(cached) => {
+            if debug {
+                eprintln!("[DEBUG] Using results cached {}s ago", cached.age());
+            }
+            cached.results
+        }
+        None if config.cache.offline => return Err(format!("'{}' is not in the cache (offline)", args.query).into()),
+        None => {
+            let raw = client.search(&args.query, Some(indexers), filter_nsfw)?;
+            if let Err(e) = SearchCache::put(&args.query, indexers, filter_nsfw, &raw) {
+                if debug {
+                    eprintln!("[DEBUG] Failed to cache results: {}", e);
+                }
+            }
+            raw
+        }
+    };
+
+    let mut results = merge_duplicates(raw);
+    filters.apply(&mut results);
+    if let Some(limit) = args.limit {
+        results.truncate(limit);
+    }
+
+    Ok(results)
+}
+
+fn search(args: &SearchArgs, config: &Config, registry: &Arc<IndexerRegistry>, debug: bool) -> CliResult {
+    let client = chill_client(config)?;
+    let indexers = registry.api_ids(&args.indexers).map_err(CliError::usage)?;
+    let results = fetch_results(&client, args, &indexers, config, registry, debug)?;
+
+    if results.is_empty() {
+        eprintln!("No results for '{}'", args.query);
+        // Keep JSON consumers happy with a valid (empty) document
+        if args.format == OutputFormat::Json {
+            println!("[]");
+        }
+        return Ok(EXIT_NO_RESULTS);
+    }
+
+    output::print_results(&results, args.format, registry)?;
+    Ok(EXIT_OK)
+}
+
+/// Interactive results table; the chosen results are printed to stdout
+fn pick(args: &SearchArgs, config: &Config, registry: &Arc<IndexerRegistry>, debug: bool) -> CliResult {
+    chill_client(config)?;
+
+    let mut app = App::new(config.clone(), Arc::clone(registry), debug);
+    app.set_filters(&args.indexers, args.min_seeds, args.sort, !args.allow_nsfw, args.hide_suspicious);
+
+    match app.run_picker(&args.query)? {
+        Some(picked) => {
+            output::print_results(&picked, args.format, registry)?;
+            Ok(EXIT_OK)
+        }
+        None => Ok(EXIT_CANCELLED),
+    }
+}
+
+fn send(args: &SendArgs, config: &Config, debug: bool) -> CliResult {
+    let client = putio_client(config)?;
+
+    let folder_id = target_folder(&client, args.folder.as_deref(), config)?;
+
+    if debug {
+        eprintln!("[DEBUG] Sending {} transfers to folder {}", args.magnets.len(), folder_id);
+    }
+
+    let mut sent_log = SentLog::load();
+    let mut failed = 0;
+    for magnet in &args.magnets {
+        match client.add_transfer(magnet, folder_id) {
+            Ok(transfer) => {
+                println!("✓ Added {} ({})", transfer.name, transfer.id);
+                let parsed = magnet.parse::<Magnet>().ok();
+                if let Some(hash) = parsed.as_ref().and_then(Magnet::infohash) {
+                    sent_log.record(hash, &transfer.name);
+                }
+            }
+            Err(e) => {
+                eprintln!("✗ Failed to add {}: {}", magnet, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if let Err(e) = sent_log.save() {
+        eprintln!("✗ Failed to save sent log: {}", e);
+    }
+
+    Ok(if failed > 0 { EXIT_API } else { EXIT_OK })
+}
+
+/// Folder to send into: `path` if given (created if missing), otherwise
+/// the configured download folder
+fn target_folder(client: &PutioClient, path: Option<&str>, config: &Config) -> Result<u64, Box<dyn std::error::Error>> {
+    match (path, config.putio_folder_id) {
+        (Some(path), _) => client.find_or_create_folder_path(path),
+        (None, Some(id)) => Ok(id),
+        (None, None) => client.find_or_create_folder_path(&config.putio_folder_name),
+    }
+}
+
+fn transfers(config: &Config) -> CliResult {
+    let client = putio_client(config)?;
+    let transfers = client.list_transfers()?;
+
+    println!("{:>10}  {:<12} {:>4} {:>11}  NAME", "ID", "STATUS", "%", "SIZE");
+    for transfer in &transfers {
+        let size = transfer.size.map(TorrentResult::format_size).unwrap_or_default();
+        println!(
+            "{:>10}  {:<12} {:>4} {:>11}  {}",
+            transfer.id,
+            transfer.status,
+            transfer.percent_done.unwrap_or(0),
+            size,
+            transfer.name,
+        );
+    }
+
+    Ok(EXIT_OK)
+}
+
+/// The known indexers, with what the config changed about them
+fn indexers(registry: &IndexerRegistry) -> CliResult {
+    println!("{:<12} {:<14} {:<8} {:<8}  FILTERS", "NAME", "ID", "CATEGORY", "STATUS");
+    for indexer in registry.all() {
+        let filters = &indexer.filters;
+        let mut rules = Vec::new();
+        if let Some(min) = filters.min_seeds {
+            rules.push(format!("seeds>={}", min));
+        }
+        if let Some(min) = &filters.min_size {
+            rules.push(format!("size>={}", min));
+        }
+        if let Some(max) = &filters.max_size {
+            rules.push(format!("size<={}", max));
+        }
+        let line = format!(
+            "{:<12} {:<14} {:<8} {:<8}  {}",
+            indexer.name,
+            indexer.id,
+            indexer.category.label(),
+            if indexer.enabled { "enabled" } else { "disabled" },
+            rules.join(" "),
+        );
+        println!("{}", line.trim_end());
+    }
+
+    Ok(EXIT_OK)
+}
+
+fn folders(path: Option<&str>, config: &Config) -> CliResult {
+    let client = putio_client(config)?;
+    let parent_id = match path {
+        Some(path) => client.find_folder_path(path)?,
+        None => 0,
+    };
+
+    println!("{:>10}  NAME", "ID");
+    for folder in client.list_folders(parent_id)? {
+        println!("{:>10}  {}/", folder.id, folder.name);
+    }
+
+    Ok(EXIT_OK)
+}
@@ -0,0 +1,124 @@
+use serde::Serialize;
+
+use crate::models::{suspicions, IndexerRegistry, ReleaseInfo, TorrentResult};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Ndjson,
+    Csv,
+    Magnets,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
+            "csv" => Ok(OutputFormat::Csv),
+            "magnets" | "magnet" => Ok(OutputFormat::Magnets),
+            _ => Err(format!("unknown format '{}' (expected table, json, ndjson, csv or magnets)", s)),
+        }
+    }
+}
+
+/// A result plus the fields scripts would otherwise have to compute. The
+/// names match the CSV columns rather than the API's (`source`, `peers`,
+/// `link`), so every format reads the same.
+#[derive(Serialize)]
+struct ResultRecord<'a> {
+    title: &'a str,
+    indexer: &'a str,
+    size: u64,
+    size_human: String,
+    seeders: u32,
+    leechers: u32,
+    infohash: Option<String>,
+    magnet: &'a str,
+    /// Every indexer the torrent was found on (more than one when merged)
+    indexers: Vec<&'a str>,
+    release: ReleaseInfo,
+    /// Why the result looks like a fake or malware
+    suspicious: Vec<String>,
+}
+
+impl<'a> ResultRecord<'a> {
+    fn new(result: &'a TorrentResult, registry: &'a IndexerRegistry) -> Self {
+        Self {
+            title: &result.title,
+            indexer: &result.indexer,
+            size: result.size,
+            size_human: result.size_str(),
+            seeders: result.seeders,
+            leechers: result.leechers,
+            infohash: result.infohash(),
+            magnet: &result.magnet,
+            indexers: result.indexer_names(registry),
+            release: result.release(),
+            suspicious: suspicions(result),
+        }
+    }
+}
+
+pub fn print_results(results: &[TorrentResult], format: OutputFormat, registry: &IndexerRegistry) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        OutputFormat::Table => {
+            println!("{:>6} {:>6} {:>11}  {:<10}  TITLE", "SEEDS", "PEERS", "SIZE", "SOURCE");
+            for result in results {
+                println!(
+                    "{:>6} {:>6} {:>11}  {:<10}  {}",
+                    result.seeders,
+                    result.leechers,
+                    result.size_str(),
+                    registry.display_name(&result.indexer),
+                    if suspicions(result).is_empty() { result.title.clone() } else { format!("⚠ {}", result.title) },
+                );
+            }
+        }
+        OutputFormat::Json => {
+            let records: Vec<ResultRecord> = results.iter().map(|result| ResultRecord::new(result, registry)).collect();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        OutputFormat::Ndjson => {
+            for result in results {
+                println!("{}", serde_json::to_string(&ResultRecord::new(result, registry))?);
+            }
+        }
+        OutputFormat::Csv => {
+            println!("title,indexer,size,size_human,seeders,leechers,infohash,magnet");
+            for result in results {
+                println!(
+                    "{},{},{},{},{},{},{},{}",
+                    csv_field(&result.title),
+                    csv_field(&result.indexer),
+                    result.size,
+                    csv_field(&result.size_str()),
+                    result.seeders,
+                    result.leechers,
+                    result.infohash().unwrap_or_default(),
+                    csv_field(&result.magnet),
+                );
+            }
+        }
+        OutputFormat::Magnets => {
+            for result in results {
+                println!("{}", result.magnet);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or newline (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use super::{store, unix_now};
+use crate::models::TorrentResult;
+
+/// Entries not rewritten for this long are deleted when a new one is written
+const PRUNE_AFTER: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Chill API search responses, one file per keyword, indexers and NSFW flag
+pub struct SearchCache;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSearch {
+    /// Unix timestamp in seconds
+    pub fetched_at: u64,
+    pub keyword: String,
+    pub indexers: Vec<String>,
+    pub filter_nsfw: bool,
+    /// The response as the API returned it, before merging duplicates
+    pub results: Vec<TorrentResult>,
+}
+
+impl CachedSearch {
+    /// Seconds since the response was fetched
+    pub fn age(&self) -> u64 {
+        unix_now().saturating_sub(self.fetched_at)
+    }
+}
+
+impl SearchCache {
+    /// The cached response for a request, however old
+    pub fn get(keyword: &str, indexers: &[String], filter_nsfw: bool) -> Option<CachedSearch> {
+        let keyword = normalize_keyword(keyword);
+        let indexers = normalize_indexers(indexers);
+        let path = Self::entry_path(&keyword, &indexers, filter_nsfw).ok()?;
+        let cached: CachedSearch = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+
+        // Different requests could share a file name
+        (cached.keyword == keyword && cached.indexers == indexers && cached.filter_nsfw == filter_nsfw)
+            .then_some(cached)
+    }
+
+    /// Store a fresh response and drop entries nobody asked for in a week
+    pub fn put(keyword: &str, indexers: &[String], filter_nsfw: bool, results: &[TorrentResult]) -> Result<(), Box<dyn std::error::Error>> {
+        let keyword = normalize_keyword(keyword);
+        let indexers = normalize_indexers(indexers);
+        let path = Self::entry_path(&keyword, &indexers, filter_nsfw)?;
+
+        let cached = CachedSearch {
+            fetched_at: unix_now(),
+            keyword,
+            indexers,
+            filter_nsfw,
+            results: results.to_vec(),
+        };
+
+        // Written atomically, so a reader never sees half an entry
+        store::write(&path, &serde_json::to_string(&cached)?)?;
+
+        Self::prune(&Self::dir()?);
+        Ok(())
+    }
+
+    pub fn dir() -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let dirs = directories::ProjectDirs::from("", "", "chilltui")
+            .ok_or("Could not determine cache directory")?;
+        Ok(dirs.cache_dir().join("searches"))
+    }
+
+    /// File named by an FNV-1a hash of the request, which unlike std's
+    /// hasher stays the same across Rust releases
+    fn entry_path(keyword: &str, indexers: &[String], filter_nsfw: bool) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let request = format!("{}\0{}\0{}", keyword, indexers.join(","), filter_nsfw);
+        let hash = request.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        Ok(Self::dir()?.join(format!("{:016x}.json", hash)))
+    }
+
+    fn prune(dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else { return };
+        for entry in entries.flatten() {
+            let expired = entry.metadata()
+                .and_then(|meta| meta.modified())
+                .is_ok_and(|modified| SystemTime::now().duration_since(modified).is_ok_and(|age| age > PRUNE_AFTER));
+            if expired {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+/// Trimmed and lowercase, so "Dune " and "dune" share an entry
+fn normalize_keyword(keyword: &str) -> String {
+    keyword.trim().to_lowercase()
+}
+
+/// Lowercase, sorted and deduplicated; "all" or none at all become empty
+fn normalize_indexers(indexers: &[String]) -> Vec<String> {
+    if indexers.iter().any(|i| i.eq_ignore_ascii_case("all")) {
+        return Vec::new();
+    }
+    let mut normalized: Vec<String> = indexers.iter().map(|i| i.to_lowercase()).collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CacheConfig;
+
+    fn file_name(keyword: &str, indexers: &[&str], filter_nsfw: bool) -> String {
+        let indexers: Vec<String> = indexers.iter().map(|i| i.to_string()).collect();
+        let path = SearchCache::entry_path(
+            &normalize_keyword(keyword),
+            &normalize_indexers(&indexers),
+            filter_nsfw,
+        )
+        .expect("cache directory");
+        path.file_name().unwrap().to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn names_entries_the_same_on_every_build() {
+        assert_eq!(file_name("dune", &["eztv", "yts"], true), "b4e97c16a763891a.json");
+    }
+
+    #[test]
+    fn ignores_keyword_case_and_spacing_and_indexer_order() {
+        let name = file_name("dune", &["eztv", "yts"], true);
+        assert_eq!(file_name("  Dune ", &["YTS", "eztv", "yts"], true), name);
+        assert_ne!(file_name("dune", &["eztv", "yts"], false), name);
+        assert_ne!(file_name("dune 2", &["eztv", "yts"], true), name);
+        assert_eq!(file_name("dune", &["all"], true), file_name("dune", &[], true));
+    }
+
+    #[test]
+    fn serves_entries_younger_than_the_ttl() {
+        let config = CacheConfig { ttl_minutes: 30, offline: false };
+        assert!(config.is_fresh(29 * 60));
+        assert!(!config.is_fresh(30 * 60));
+        assert!(CacheConfig { offline: true, ..config }.is_fresh(u64::MAX));
+    }
+}
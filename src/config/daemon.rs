@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{store, unix_now};
+
+const FILE: &str = "daemon.json";
+
+/// What `chilltui daemon` has seen of each watch, keyed by saved search name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DaemonState {
+    #[serde(default)]
+    watches: HashMap<String, WatchState>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WatchState {
+    /// Unix timestamp in seconds of the last successful run
+    pub last_run: u64,
+    /// Infohashes the last run returned
+    pub seen: Vec<String>,
+}
+
+impl DaemonState {
+    /// Load the state; every watch starts over if the file is unreadable
+    pub fn load() -> Self {
+        store::load(FILE)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        store::write(&store::data_path(FILE)?, &serde_json::to_string(self)?)
+    }
+
+    pub fn get(&self, watch: &str) -> Option<&WatchState> {
+        self.watches.get(watch)
+    }
+
+    /// Remember the infohashes a run of `watch` returned
+    pub fn record(&mut self, watch: &str, seen: Vec<String>) {
+        self.watches.insert(watch.to_string(), WatchState { last_run: unix_now(), seen });
+    }
+}
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+
+use super::{store, unix_now};
+use crate::models::{Filters, SortOrder};
+
+const FILE: &str = "history.json";
+
+/// Oldest entries beyond this are dropped on save
+const MAX_ENTRIES: usize = 500;
+
+/// Past searches, ranked by frecency for recall in the search bar
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    entries: Vec<HistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub query: String,
+    /// Filter panel state the search last ran with
+    pub filters: FilterSnapshot,
+    /// Results shown the last time the search completed
+    pub result_count: Option<usize>,
+    /// How many times the query was searched
+    pub uses: u32,
+    /// Unix timestamp in seconds
+    pub last_used: u64,
+}
+
+/// The serializable part of `Filters`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterSnapshot {
+    pub sort: SortOrder,
+    pub indexers: Vec<String>,
+    pub min_seeds: u32,
+    pub max_seeds: Option<u32>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    pub filter_nsfw: bool,
+}
+
+impl From<&Filters> for FilterSnapshot {
+    fn from(filters: &Filters) -> Self {
+        Self {
+            sort: filters.sort,
+            indexers: filters.indexers.clone(),
+            min_seeds: filters.min_seeds,
+            max_seeds: filters.max_seeds,
+            min_size: filters.min_size,
+            max_size: filters.max_size,
+            filter_nsfw: filters.filter_nsfw,
+        }
+    }
+}
+
+impl FilterSnapshot {
+    /// Put the snapshot back into `filters`, leaving the blocklist and
+    /// inline terms alone
+    pub fn restore(&self, filters: &mut Filters) {
+        filters.sort = self.sort;
+        filters.indexers = self.indexers.clone();
+        filters.min_seeds = self.min_seeds;
+        filters.max_seeds = self.max_seeds;
+        filters.min_size = self.min_size;
+        filters.max_size = self.max_size;
+        filters.filter_nsfw = self.filter_nsfw;
+    }
+}
+
+impl HistoryEntry {
+    /// Use count weighted by how recently the query was used, in the style
+    /// of Firefox's frecency
+    pub fn frecency(&self, now: u64) -> u64 {
+        let age_days = now.saturating_sub(self.last_used) / 86_400;
+        let weight = match age_days {
+            0..=3 => 100,
+            4..=14 => 70,
+            15..=31 => 50,
+            32..=90 => 30,
+            _ => 10,
+        };
+        u64::from(self.uses) * weight
+    }
+}
+
+impl History {
+    /// Load the history, empty if the file is missing or unreadable
+    pub fn load() -> Self {
+        store::load(FILE)
+    }
+
+    pub fn save(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.prune();
+        store::write(&store::data_path(FILE)?, &serde_json::to_string(self)?)
+    }
+
+    /// Keep only the `MAX_ENTRIES` most recently used entries
+    fn prune(&mut self) {
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+            self.entries.truncate(MAX_ENTRIES);
+        }
+    }
+
+    /// Record a search for `query`, bumping it if it was searched before
+    pub fn record(&mut self, query: &str, filters: &Filters) {
+        let query = query.trim();
+        let now = unix_now();
+
+        match self.entries.iter_mut().find(|e| e.query == query) {
+            Some(entry) => {
+                entry.uses += 1;
+                entry.last_used = now;
+                entry.filters = filters.into();
+            }
+            None => self.entries.push(HistoryEntry {
+                query: query.to_string(),
+                filters: filters.into(),
+                result_count: None,
+                uses: 1,
+                last_used: now,
+            }),
+        }
+    }
+
+    pub fn set_result_count(&mut self, query: &str, count: usize) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.query == query.trim()) {
+            entry.result_count = Some(count);
+        }
+    }
+
+    /// Entries containing `needle` (any case), best frecency first
+    pub fn matches(&self, needle: &str) -> Vec<&HistoryEntry> {
+        let needle = needle.trim().to_lowercase();
+        let now = unix_now();
+
+        let mut matches: Vec<&HistoryEntry> = self.entries.iter()
+            .filter(|e| e.query.to_lowercase().contains(&needle))
+            .collect();
+        matches.sort_by_key(|e| (std::cmp::Reverse(e.frecency(now)), std::cmp::Reverse(e.last_used)));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 86_400;
+
+    fn entry(query: &str, uses: u32, last_used: u64) -> HistoryEntry {
+        HistoryEntry {
+            query: query.to_string(),
+            filters: FilterSnapshot::default(),
+            result_count: None,
+            uses,
+            last_used,
+        }
+    }
+
+    fn queries(entries: Vec<&HistoryEntry>) -> Vec<&str> {
+        entries.into_iter().map(|e| e.query.as_str()).collect()
+    }
+
+    #[test]
+    fn weighs_uses_by_recency() {
+        let now = 365 * DAY;
+        assert_eq!(entry("dune", 3, now).frecency(now), 300);
+        assert_eq!(entry("dune", 3, now - 10 * DAY).frecency(now), 210);
+        assert_eq!(entry("dune", 3, now - 200 * DAY).frecency(now), 30);
+    }
+
+    #[test]
+    fn orders_matches_by_frecency_then_recency() {
+        let now = unix_now();
+        let history = History {
+            entries: vec![
+                // Used often, but months ago: 5 * 10
+                entry("dune 1984", 5, now - 120 * DAY),
+                // Used twice this week: 2 * 100
+                entry("dune part two", 2, now - DAY),
+                // Used once today: 1 * 100
+                entry("dune part one", 1, now),
+                // Ties with "part one" but was used less recently
+                entry("Dune Messiah", 1, now - 2 * DAY),
+                entry("arrival", 9, now),
+            ],
+        };
+
+        assert_eq!(
+            queries(history.matches(" DUNE ")),
+            ["dune part two", "dune part one", "Dune Messiah", "dune 1984"]
+        );
+        assert_eq!(queries(history.matches("part")), ["dune part two", "dune part one"]);
+    }
+
+    #[test]
+    fn prunes_to_the_newest_entries() {
+        let mut history = History {
+            entries: (0..MAX_ENTRIES as u64 + 10).map(|i| entry(&i.to_string(), 1, i)).collect(),
+        };
+        history.prune();
+
+        assert_eq!(history.entries.len(), MAX_ENTRIES);
+        assert!(history.entries.iter().all(|e| e.last_used >= 10));
+    }
+}
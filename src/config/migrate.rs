@@ -0,0 +1,63 @@
+use serde_json::{json, Value};
+
+use super::CONFIG_VERSION;
+
+/// Upgrade a raw config document to `CONFIG_VERSION`.
+///
+/// Returns the upgraded document and whether anything changed. Each step
+/// only knows how to go from version N to N + 1, so old files are walked
+/// forward one version at a time.
+pub fn migrate(mut value: Value) -> Result<(Value, bool), Box<dyn std::error::Error>> {
+    let obj = value.as_object_mut().ok_or("Config file is not a JSON object")?;
+    let mut version = obj.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+
+    if version > CONFIG_VERSION {
+        return Err(format!(
+            "Config version {} is newer than this chilltui supports ({})",
+            version, CONFIG_VERSION
+        )
+        .into());
+    }
+
+    let migrated = version < CONFIG_VERSION;
+
+    while version < CONFIG_VERSION {
+        match version {
+            0 => v0_to_v1(obj),
+            1 => v1_to_v2(obj),
+            _ => unreachable!("missing config migration from version {}", version),
+        }
+        version += 1;
+    }
+
+    obj.insert("version".to_string(), json!(version));
+    Ok((value, migrated))
+}
+
+/// v0 files predate versioning: UI preferences were not stored and the
+/// folder name could be left empty by an interrupted setup.
+fn v0_to_v1(obj: &mut serde_json::Map<String, Value>) {
+    let folder_missing = obj
+        .get("putio_folder_name")
+        .and_then(Value::as_str)
+        .is_none_or(str::is_empty);
+    if folder_missing {
+        obj.insert("putio_folder_name".to_string(), json!("ChillTUI"));
+    }
+
+    obj.entry("ui").or_insert_with(|| json!({}));
+}
+
+/// v1 stored a bare `ui.sort_by` mode; v2 has a `ui.sort` object with a
+/// direction and tie-breaker. The direction used to be fixed per mode.
+fn v1_to_v2(obj: &mut serde_json::Map<String, Value>) {
+    let Some(ui) = obj.get_mut("ui").and_then(Value::as_object_mut) else {
+        return;
+    };
+
+    let key = ui.remove("sort_by")
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| "seeders".to_string());
+    let descending = key != "name";
+    ui.insert("sort".to_string(), json!({ "key": key, "descending": descending, "then_by": null }));
+}
@@ -1,13 +1,164 @@
+mod cache;
+mod daemon;
+mod history;
+mod migrate;
+mod saved;
+mod sent;
+mod series;
+mod store;
+
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+use crate::models::{AcceptRules, Blocklist, IndexerEntry, QualityProfile, SortOrder};
+
+pub use cache::SearchCache;
+pub use daemon::DaemonState;
+pub use history::History;
+pub use saved::SavedSearches;
+pub use sent::{unix_now, SentLog};
+pub use series::SeriesTracker;
+
+/// Current on-disk config schema version. Bump this and add a step in
+/// `migrate.rs` whenever the layout of `config.json` changes.
+pub const CONFIG_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    #[serde(default)]
+    pub version: u32,
     pub chill_api_key: Option<String>,
     pub putio_oauth_token: Option<String>,
     pub putio_folder_id: Option<u64>,
     pub putio_folder_name: String,
+    #[serde(default)]
+    pub ui: UiPreferences,
+    #[serde(default)]
+    pub blocklist: Blocklist,
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    #[serde(default)]
+    pub profiles: Vec<QualityProfile>,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    /// Changes to the built-in indexers and extra ones, see `IndexerEntry`
+    #[serde(default)]
+    pub indexers: Vec<IndexerEntry>,
+}
+
+/// Filter, sort and layout choices remembered between launches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiPreferences {
+    pub sort: SortOrder,
+    pub selected_indexers: Vec<String>,
+    pub min_seeds: u32,
+    pub max_seeds: Option<u32>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    pub filter_nsfw: bool,
+    /// Show the detail pane under the results table
+    pub show_details: bool,
+    /// Hide results that look like fakes or malware
+    pub hide_suspicious: bool,
+    /// Name of the quality profile results are scored against
+    pub profile: Option<String>,
+}
+
+impl Default for UiPreferences {
+    fn default() -> Self {
+        Self {
+            sort: SortOrder::default(),
+            selected_indexers: vec!["all".to_string()],
+            min_seeds: 10,
+            max_seeds: None,
+            min_size: None,
+            max_size: None,
+            filter_nsfw: true,
+            show_details: false,
+            hide_suspicious: false,
+            profile: None,
+        }
+    }
+}
+
+/// Saved searches `chilltui daemon` runs on a schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DaemonConfig {
+    /// Minutes between runs
+    pub interval_minutes: u64,
+    pub watch: Vec<Watch>,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            interval_minutes: 60,
+            watch: Vec::new(),
+        }
+    }
+}
+
+/// How long cached search results count as fresh
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Minutes before a cached search is refreshed in the background
+    pub ttl_minutes: u64,
+    /// Serve searches only from the cache. Set by `--offline`, never saved.
+    #[serde(skip)]
+    pub offline: bool,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_minutes: 30,
+            offline: false,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Whether a cache entry `age_secs` old can be used without a refresh
+    pub fn is_fresh(&self, age_secs: u64) -> bool {
+        self.offline || age_secs < self.ttl_minutes * 60
+    }
+}
+
+/// A saved search the daemon runs, and what it may send from the results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watch {
+    /// Name of the saved search
+    pub search: String,
+    /// Put.io folder path to send into instead of the default folder
+    #[serde(default)]
+    pub folder: Option<String>,
+    /// Quality profile results must pass; the best scored one is sent
+    #[serde(default)]
+    pub profile: Option<String>,
+    #[serde(flatten)]
+    pub rules: AcceptRules,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            chill_api_key: None,
+            putio_oauth_token: None,
+            putio_folder_id: None,
+            putio_folder_name: "ChillTUI".to_string(),
+            ui: UiPreferences::default(),
+            blocklist: Blocklist::default(),
+            daemon: DaemonConfig::default(),
+            profiles: Vec::new(),
+            cache: CacheConfig::default(),
+            indexers: Vec::new(),
+        }
+    }
 }
 
 impl Config {
@@ -15,27 +166,25 @@ impl Config {
         let config_path = Self::config_path()?;
 
         if !config_path.exists() {
-            return Ok(Config {
-                putio_folder_name: "ChillTUI".to_string(),
-                ..Default::default()
-            });
+            return Ok(Config::default());
         }
 
         let content = fs::read_to_string(&config_path)?;
-        let config: Config = serde_json::from_str(&content)?;
+        let value: serde_json::Value = serde_json::from_str(&content)?;
+        let (value, migrated) = migrate::migrate(value)?;
+        let config: Config = serde_json::from_value(value)?;
+
+        // Write the upgraded file back so the migration only runs once. A
+        // read-only config still loads; it's just migrated again next time.
+        if migrated {
+            let _ = config.save();
+        }
+
         Ok(config)
     }
 
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config_path = Self::config_path()?;
-
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        let json = serde_json::to_string_pretty(self)?;
-        fs::write(&config_path, json)?;
-        Ok(())
+        store::write(&Self::config_path()?, &serde_json::to_string_pretty(self)?)
     }
 
     pub fn config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
@@ -44,7 +193,17 @@ impl Config {
         Ok(dirs.config_dir().join("config.json"))
     }
 
+    /// The Put.io folder as shown to the user, "/" for the root
+    pub fn folder_label(&self) -> String {
+        let name = self.putio_folder_name.trim_matches('/');
+        if name.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}/", name)
+        }
+    }
+
     pub fn needs_setup(&self) -> bool {
         self.chill_api_key.is_none() || self.putio_oauth_token.is_none()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::history::FilterSnapshot;
+use super::{store, unix_now};
+use crate::models::{Filters, TorrentResult};
+
+const FILE: &str = "saved.json";
+
+/// Named searches kept in the sidebar and re-run on demand
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedSearches {
+    #[serde(default)]
+    searches: Vec<SavedSearch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    pub query: String,
+    pub filters: FilterSnapshot,
+    /// Unix timestamp in seconds of the last run
+    #[serde(default)]
+    pub last_run: Option<u64>,
+    /// Infohashes the last run returned
+    #[serde(default)]
+    pub seen: Vec<String>,
+    /// Infohashes the last run returned that the run before it did not
+    #[serde(default)]
+    pub new: Vec<String>,
+}
+
+impl SavedSearch {
+    /// Compare `results` with the previous run and remember them as the
+    /// latest one. Nothing counts as new on the first run.
+    pub fn record_run(&mut self, results: &[TorrentResult]) {
+        let hashes: Vec<String> = results.iter().filter_map(|r| r.infohash()).collect();
+        let seen: HashSet<&String> = self.seen.iter().collect();
+
+        self.new = if self.last_run.is_some() {
+            hashes.iter().filter(|h| !seen.contains(h)).cloned().collect()
+        } else {
+            Vec::new()
+        };
+        self.seen = hashes;
+        self.last_run = Some(unix_now());
+    }
+}
+
+impl SavedSearches {
+    /// Load the saved searches, none if the file is missing or unreadable
+    pub fn load() -> Self {
+        store::load(FILE)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        store::write(&store::data_path(FILE)?, &serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn list(&self) -> &[SavedSearch] {
+        &self.searches
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SavedSearch> {
+        self.searches.iter().find(|s| s.name == name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SavedSearch> {
+        self.searches.iter_mut().find(|s| s.name == name)
+    }
+
+    /// Save `query` with `filters` under `name`, replacing the query of a
+    /// search with the same name but keeping what it has seen
+    pub fn add(&mut self, name: &str, query: &str, filters: &Filters) {
+        let name = name.trim();
+        match self.get_mut(name) {
+            Some(search) => {
+                search.query = query.trim().to_string();
+                search.filters = filters.into();
+            }
+            None => self.searches.push(SavedSearch {
+                name: name.to_string(),
+                query: query.trim().to_string(),
+                filters: filters.into(),
+                last_run: None,
+                seen: Vec::new(),
+                new: Vec::new(),
+            }),
+        }
+    }
+
+    /// Whether a search called `name` existed
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.searches.len();
+        self.searches.retain(|s| s.name != name);
+        self.searches.len() != before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn torrent(hash: char) -> TorrentResult {
+        TorrentResult {
+            magnet: format!("magnet:?xt=urn:btih:{}", hash.to_string().repeat(40)),
+            ..TorrentResult::sample(&format!("Dune {}", hash))
+        }
+    }
+
+    fn search() -> SavedSearch {
+        let mut searches = SavedSearches::default();
+        searches.add(" dune ", "dune 2160p", &Filters::default());
+        searches.get("dune").cloned().expect("saved search")
+    }
+
+    #[test]
+    fn marks_nothing_new_on_the_first_run() {
+        let mut search = search();
+        search.record_run(&[torrent('a'), torrent('b')]);
+
+        assert!(search.last_run.is_some());
+        assert_eq!(search.seen.len(), 2);
+        assert!(search.new.is_empty());
+    }
+
+    #[test]
+    fn marks_only_unseen_hashes_new_on_later_runs() {
+        let mut search = search();
+        search.record_run(&[torrent('a'), torrent('b')]);
+        search.record_run(&[torrent('b'), torrent('c'), TorrentResult::sample("No magnet")]);
+
+        assert_eq!(search.new, ["c".repeat(40)]);
+        assert_eq!(search.seen, ["b".repeat(40), "c".repeat(40)]);
+
+        search.record_run(&[torrent('b'), torrent('c')]);
+        assert!(search.new.is_empty());
+    }
+}
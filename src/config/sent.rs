@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::store;
+
+const FILE: &str = "sent.json";
+
+/// Torrents already sent to Put.io, keyed by infohash
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SentLog {
+    #[serde(default)]
+    entries: Vec<SentEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentEntry {
+    pub infohash: String,
+    pub title: String,
+    /// Unix timestamp in seconds
+    pub sent_at: u64,
+}
+
+impl SentLog {
+    /// Load the log; a missing or unreadable file is an empty log
+    pub fn load() -> Self {
+        store::load(FILE)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        store::write(&store::data_path(FILE)?, &serde_json::to_string(self)?)
+    }
+
+    /// Remember that `infohash` was sent just now
+    pub fn record(&mut self, infohash: &str, title: &str) {
+        self.entries.retain(|e| e.infohash != infohash);
+        self.entries.push(SentEntry {
+            infohash: infohash.to_string(),
+            title: title.to_string(),
+            sent_at: unix_now(),
+        });
+    }
+
+    pub fn get(&self, infohash: &str) -> Option<&SentEntry> {
+        self.entries.iter().find(|e| e.infohash == infohash)
+    }
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use super::{store, unix_now};
+use crate::models::release::Episode;
+use crate::models::series::is_release_of;
+use crate::models::{ReleaseInfo, TorrentResult};
+
+const FILE: &str = "series.json";
+
+/// Shows being followed episode by episode
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeriesTracker {
+    #[serde(default)]
+    shows: Vec<TrackedShow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedShow {
+    pub name: String,
+    /// Next expected episode
+    pub season: u16,
+    pub episode: u16,
+    /// Title of the last release sent
+    #[serde(default)]
+    pub last_sent: Option<String>,
+    /// Unix timestamp in seconds
+    #[serde(default)]
+    pub last_sent_at: Option<u64>,
+}
+
+impl TrackedShow {
+    /// "S02E04" for the next expected episode
+    pub fn next_label(&self) -> String {
+        format!("S{:02}E{:02}", self.season, self.episode)
+    }
+
+    /// Queries to try in order for the next episode, with the episode each
+    /// one has to match: the episode itself, a pack of its season, and the
+    /// start of the following season in case this one has ended
+    pub fn search_plan(&self) -> Vec<(String, u16, u16)> {
+        let mut plan = vec![
+            (format!("{} {}", self.name, self.next_label()), self.season, self.episode),
+            (format!("{} S{:02}", self.name, self.season), self.season, self.episode),
+        ];
+        if let Some(next_season) = self.season.checked_add(1).filter(|_| self.episode > 1) {
+            plan.push((format!("{} S{:02}E01", self.name, next_season), next_season, 1));
+        }
+        plan
+    }
+
+    /// Move the next expected episode forward to (`season`, `episode`),
+    /// never back
+    fn advance_to(&mut self, season: u16, episode: u16) {
+        if (season, episode) > (self.season, self.episode) {
+            self.season = season;
+            self.episode = episode;
+        }
+    }
+}
+
+impl SeriesTracker {
+    /// Load the tracked shows, none if the file is missing or unreadable
+    pub fn load() -> Self {
+        store::load(FILE)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        store::write(&store::data_path(FILE)?, &serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn list(&self) -> &[TrackedShow] {
+        &self.shows
+    }
+
+    /// Track a show given as "Name", "Name S02" or "Name S02E04", starting
+    /// at that episode (S01E01 by default). Returns the show's name.
+    pub fn add(&mut self, input: &str) -> Option<String> {
+        let mut words: Vec<&str> = input.split_whitespace().collect();
+        let (season, episode) = match words.last().and_then(|w| ReleaseInfo::parse(w).episode) {
+            Some(Episode::Single { season, episode, .. }) => {
+                words.pop();
+                (season, episode)
+            }
+            Some(Episode::Season(season)) => {
+                words.pop();
+                (season, 1)
+            }
+            _ => (1, 1),
+        };
+
+        let name = words.join(" ");
+        if name.is_empty() {
+            return None;
+        }
+
+        self.shows.retain(|show| !show.name.eq_ignore_ascii_case(&name));
+        self.shows.push(TrackedShow { name: name.clone(), season, episode, last_sent: None, last_sent_at: None });
+        Some(name)
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.shows.retain(|show| show.name != name);
+    }
+
+    /// Step the next expected episode of `name` by one, forward or back.
+    /// Going back from E01 stays put, since season lengths aren't known.
+    pub fn step(&mut self, name: &str, forward: bool) {
+        if let Some(show) = self.shows.iter_mut().find(|show| show.name == name) {
+            if forward {
+                show.episode = show.episode.checked_add(1).unwrap_or(show.episode);
+            } else if show.episode > 1 {
+                show.episode -= 1;
+            }
+        }
+    }
+
+    /// Move tracked shows past `result` if it is one of their episodes or
+    /// season packs. Returns whether any show moved.
+    pub fn record_sent(&mut self, result: &TorrentResult) -> bool {
+        let Some((season, episode)) = result.release().episode.and_then(|e| e.next()) else {
+            return false;
+        };
+
+        let mut moved = false;
+        for show in self.shows.iter_mut().filter(|show| is_release_of(&result.title, &show.name)) {
+            let before = (show.season, show.episode);
+            show.advance_to(season, episode);
+            if (show.season, show.episode) != before {
+                show.last_sent = Some(result.title.clone());
+                show.last_sent_at = Some(unix_now());
+                moved = true;
+            }
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(input: &str) -> SeriesTracker {
+        let mut tracker = SeriesTracker::default();
+        tracker.add(input);
+        tracker
+    }
+
+    fn next(tracker: &SeriesTracker) -> (u16, u16) {
+        (tracker.list()[0].season, tracker.list()[0].episode)
+    }
+
+    #[test]
+    fn adds_a_show_at_the_given_episode() {
+        assert_eq!(next(&tracker("Severance")), (1, 1));
+        assert_eq!(next(&tracker("Severance S02")), (2, 1));
+        assert_eq!(next(&tracker("Severance S02E04")), (2, 4));
+        assert_eq!(tracker("Severance S02E04").list()[0].name, "Severance");
+    }
+
+    #[test]
+    fn plans_the_episode_its_season_and_the_next_season() {
+        assert_eq!(tracker("Severance S02E04").list()[0].search_plan(), [
+            ("Severance S02E04".to_string(), 2, 4),
+            ("Severance S02".to_string(), 2, 4),
+            ("Severance S03E01".to_string(), 3, 1),
+        ]);
+
+        // A season that hasn't started yet has no following one to try
+        assert_eq!(tracker("Severance S02").list()[0].search_plan().len(), 2);
+    }
+
+    #[test]
+    fn steps_episodes_but_not_below_the_first() {
+        let mut tracker = tracker("Severance S02E02");
+        tracker.step("Severance", true);
+        assert_eq!(next(&tracker), (2, 3));
+        tracker.step("Severance", false);
+        tracker.step("Severance", false);
+        tracker.step("Severance", false);
+        assert_eq!(next(&tracker), (2, 1));
+    }
+
+    #[test]
+    fn moves_past_a_sent_episode_or_season() {
+        let mut tracker = tracker("Severance S02E04");
+
+        assert!(tracker.record_sent(&TorrentResult::sample("Severance S02E04 1080p")));
+        assert_eq!(next(&tracker), (2, 5));
+        assert_eq!(tracker.list()[0].last_sent.as_deref(), Some("Severance S02E04 1080p"));
+
+        // Older episodes and other shows leave it alone
+        assert!(!tracker.record_sent(&TorrentResult::sample("Severance S02E01 1080p")));
+        assert!(!tracker.record_sent(&TorrentResult::sample("Severance Island S05E01 1080p")));
+        assert_eq!(next(&tracker), (2, 5));
+
+        assert!(tracker.record_sent(&TorrentResult::sample("Severance S02 1080p")));
+        assert_eq!(next(&tracker), (3, 1));
+    }
+}
@@ -0,0 +1,36 @@
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `file` in the data directory, e.g. `~/.local/share/chilltui/sent.json`
+pub fn data_path(file: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dirs = directories::ProjectDirs::from("", "", "chilltui")
+        .ok_or("Could not determine data directory")?;
+    Ok(dirs.data_dir().join(file))
+}
+
+/// Read a JSON store from the data directory, starting from the default if
+/// it is missing or unreadable
+pub fn load<T: DeserializeOwned + Default>(file: &str) -> T {
+    data_path(file)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Replace `path` with `contents`, creating its directory if needed. The
+/// file is written next to it and renamed over it, so a crash mid-write
+/// leaves the previous version rather than a truncated one.
+pub fn write(path: &Path, contents: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
@@ -1,15 +1,20 @@
 mod api;
+mod cli;
 mod config;
 mod models;
 mod ui;
 
+use std::sync::Arc;
+
 use config::Config;
+use models::IndexerRegistry;
 use ui::setup::run_setup_wizard;
 use ui::App;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = std::env::args().collect();
     let debug = args.contains(&"--debug".to_string()) || args.contains(&"--logging".to_string());
+    let offline = args.contains(&"--offline".to_string());
 
     if args.contains(&"--help".to_string()) || args.contains(&"-h".to_string()) {
         print_help();
@@ -21,6 +26,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    // Subcommands run headless and exit with a meaningful status code
+    let command = match cli::Command::parse(&args[1..]) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("chilltui: {}", e);
+            eprintln!("Run 'chilltui --help' for usage.");
+            std::process::exit(cli::EXIT_USAGE);
+        }
+    };
+
     // Load or create config
     let mut config = Config::load().unwrap_or_else(|e| {
         if debug {
@@ -28,10 +43,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         Config::default()
     });
+    config.cache.offline = offline;
+    let mut registry = load_indexers(&config);
+
+    if let Some(command) = command {
+        std::process::exit(cli::run(command, &config, &registry, debug));
+    }
 
     // Check if setup is needed
     if config.needs_setup() || args.contains(&"--setup".to_string()) {
         config = run_setup_wizard()?;
+        config.cache.offline = offline;
+        registry = load_indexers(&config);
     }
 
     if debug {
@@ -40,31 +63,97 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Run app
-    let mut app = App::new(config, debug);
+    let mut app = App::new(config, registry, debug);
     app.run()?;
 
     Ok(())
 }
 
+/// The built-in indexers with the config's changes applied
+fn load_indexers(config: &Config) -> Arc<IndexerRegistry> {
+    match IndexerRegistry::from_entries(&config.indexers) {
+        Ok(registry) => Arc::new(registry),
+        Err(e) => {
+            eprintln!("chilltui: invalid indexers in config: {}", e);
+            std::process::exit(cli::EXIT_CONFIG);
+        }
+    }
+}
+
 fn print_help() {
     println!("chilltui v{}", env!("CARGO_PKG_VERSION"));
     println!("Fast terminal UI for torrent search via chill.institute and Put.io integration\n");
     println!("USAGE:");
-    println!("    chilltui [OPTIONS]\n");
+    println!("    chilltui [OPTIONS]");
+    println!("    chilltui <COMMAND> [ARGS]\n");
+    println!("COMMANDS:");
+    println!("    search QUERY     Search and print results");
+    println!("        -i, --indexer NAME    Limit to an indexer or category: general, movies,");
+    println!("                              tv, anime (repeatable or comma-separated)");
+    println!("        --min-seeds N         Hide results with fewer than N seeders");
+    println!("        --sort KEY[:DIR]      seeders (default), leechers, ratio, size, name,");
+    println!("                              indexer or resolution; DIR is asc or desc");
+    println!("        --then KEY            Tie-breaker for equal results");
+    println!("        -n, --limit N         Print at most N results");
+    println!("        -o, --format FMT      table (default), json, ndjson, csv or magnets");
+    println!("        --nsfw                Include NSFW results");
+    println!("        --hide-suspicious     Hide results that look like fakes or malware");
+    println!("    pick QUERY       Choose results interactively and print them to stdout");
+    println!("                     Takes the search options; --format defaults to magnets");
+    println!("    send MAGNET...   Add transfers to Put.io ('-' reads magnets from stdin)");
+    println!("        -f, --folder PATH     Save into PATH (created if missing)");
+    println!("    transfers        List Put.io transfers");
+    println!("    folders [PATH]   List Put.io folders under PATH (default: root)");
+    println!("    indexers         List the known indexers and their settings");
+    println!("    daemon           Run watched saved searches on a schedule and send new matches");
+    println!("        --once                Run every watch once and exit");
+    println!("        --dry-run             Log what would be sent without sending");
+    println!("        --interval MINUTES    Time between runs (default: config, 60)\n");
+    println!("EXIT CODES:");
+    println!("    0  Success");
+    println!("    1  Search returned no results");
+    println!("    2  Invalid arguments");
+    println!("    3  Not configured (run --setup)");
+    println!("    4  API or network error");
+    println!("    130  Picker cancelled\n");
     println!("OPTIONS:");
     println!("    -h, --help       Print help information");
     println!("    -v, --version    Print version information");
     println!("    --setup          Run setup wizard");
     println!("    --debug          Enable debug logging to stderr");
-    println!("    --logging        Same as --debug\n");
+    println!("    --logging        Same as --debug");
+    println!("    --offline        Serve searches only from the cache (app, search, pick)\n");
     println!("CONTROLS:");
     println!("    Type            Search torrents");
-    println!("    Enter           Execute search / Send to Put.io");
+    println!("    Enter           Execute search / Send to Put.io / Edit a seed or size");
+    println!("                    limit in the Filters panel (e.g. 700M, 4.5G)");
     println!("    ↑↓              Navigate results");
+    println!("    ↑↓ (search bar) Recall past searches, ranked by frecency");
+    println!("    Ctrl-R          Reverse search through history");
+    println!("    →←              Expand/collapse a result found on several indexers");
     println!("    Space           Select/deselect result");
+    println!("    /               Fuzzy-filter the current results (ESC clears)");
+    println!("    i               Toggle the detail pane");
+    println!("    p / s           Switch quality profile / select the best scored result");
+    println!("    !               Hide/show results flagged as suspicious (⚠)");
+    println!("    Ctrl-S          Save the current search with its filters");
+    println!("    Enter / d       Run / delete a saved search in the Saved panel");
+    println!("    a / g           Track a show / grab its next episode in the Shows panel");
+    println!("    + / -           Step a tracked show's next episode");
+    println!("    b / B           Block the result's release group / infohash");
     println!("    Tab             Switch focus (search/results)");
-    println!("    ESC             Clear search");
+    println!("    F2              Open settings (API key, Put.io token, folder)");
+    println!("    ESC             Cancel a running search / clear search");
     println!("    ESC×2           Quit application\n");
+    println!("SEARCH SYNTAX:");
+    println!("    seeds:>50       Minimum seeders (also <N, N..M)");
+    println!("    size:1G..8G     Size range (K/M/G/T, also >N, <N)");
+    println!("    idx:eztv,yts    Search only these indexers (or a category: idx:anime)");
+    println!("    sort:size:asc   Sort key (seeders, leechers, ratio, size, name, indexer,");
+    println!("                    resolution), optionally with :asc or :desc");
+    println!("    \"exact phrase\"  Title must contain the phrase");
+    println!("    -cam            Hide titles containing the word");
+    println!("    /regex/         Title must match the regex (-/regex/ hides matches)\n");
     println!("FIRST RUN:");
     println!("    Run without arguments to start setup wizard");
     println!("    You'll need:");
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use super::filters::TitleTerm;
+use super::{Filters, TorrentResult};
+
+/// Results that are always hidden, whatever the search
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Blocklist {
+    /// Words, phrases or `/regex/` terms matched against the title
+    pub terms: Vec<String>,
+    /// Release groups, e.g. "YIFY" (any case)
+    pub groups: Vec<String>,
+    /// Lowercase hex infohashes
+    pub infohashes: Vec<String>,
+}
+
+impl Blocklist {
+    /// Add the blocklist to a filter set
+    pub fn apply_to(&self, filters: &mut Filters) {
+        filters.excluded.extend(self.terms.iter().map(|t| TitleTerm::new(t)));
+        filters.blocked_groups.extend(self.groups.iter().map(|g| g.to_lowercase()));
+        filters.blocked_hashes.extend(self.infohashes.iter().map(|h| h.to_lowercase()));
+    }
+
+    /// Block the release group of `result`; returns the group if it was added
+    pub fn block_group(&mut self, result: &TorrentResult) -> Option<String> {
+        let group = result.release().group?;
+        if self.groups.iter().any(|g| g.eq_ignore_ascii_case(&group)) {
+            return None;
+        }
+        self.groups.push(group.clone());
+        Some(group)
+    }
+
+    /// Block `result` itself by infohash; returns the hash if it was added
+    pub fn block_infohash(&mut self, result: &TorrentResult) -> Option<String> {
+        let hash = result.infohash()?;
+        if self.infohashes.contains(&hash) {
+            return None;
+        }
+        self.infohashes.push(hash.clone());
+        Some(hash)
+    }
+}
@@ -0,0 +1,263 @@
+use regex_lite::{Regex, RegexBuilder};
+use std::sync::Arc;
+
+use super::{suspicions, IndexerRegistry, SortOrder, TorrentResult};
+
+/// Local filter and sort state applied to search results
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filters {
+    pub sort: SortOrder,
+    /// Display names of the indexers to search (or categories such as
+    /// "anime"); "all" searches every enabled indexer
+    pub indexers: Vec<String>,
+    pub min_seeds: u32,
+    pub max_seeds: Option<u32>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    /// Terms that must appear in the title
+    pub required: Vec<TitleTerm>,
+    /// Terms that must not appear in the title
+    pub excluded: Vec<TitleTerm>,
+    /// Lowercase release groups that are always hidden
+    pub blocked_groups: Vec<String>,
+    /// Infohashes that are always hidden
+    pub blocked_hashes: Vec<String>,
+    pub filter_nsfw: bool,
+    /// Hide results that look like fakes or malware
+    pub hide_suspicious: bool,
+    /// The indexers `indexers` names are looked up in
+    pub registry: Arc<IndexerRegistry>,
+}
+
+impl Default for Filters {
+    fn default() -> Self {
+        Self {
+            sort: SortOrder::default(),
+            indexers: vec!["all".to_string()],
+            min_seeds: 0,
+            max_seeds: None,
+            min_size: None,
+            max_size: None,
+            required: Vec::new(),
+            excluded: Vec::new(),
+            blocked_groups: Vec::new(),
+            blocked_hashes: Vec::new(),
+            filter_nsfw: true,
+            hide_suspicious: false,
+            registry: Arc::new(IndexerRegistry::default()),
+        }
+    }
+}
+
+impl Filters {
+    /// `selected` is what `selected_indexers` returned
+    fn matches(&self, result: &TorrentResult, selected: Option<&[&str]>) -> bool {
+        if selected.is_some_and(|selected| !self.includes_indexer(result, selected)) {
+            return false;
+        }
+        if !self.passes_indexer_filters(result) {
+            return false;
+        }
+        if result.seeders < self.min_seeds {
+            return false;
+        }
+        if self.max_seeds.is_some_and(|max| result.seeders > max) {
+            return false;
+        }
+        if self.min_size.is_some_and(|min| result.size < min) {
+            return false;
+        }
+        if self.max_size.is_some_and(|max| result.size > max) {
+            return false;
+        }
+
+        if !self.blocked_hashes.is_empty()
+            && result.infohash().is_some_and(|hash| self.blocked_hashes.contains(&hash))
+        {
+            return false;
+        }
+        if !self.blocked_groups.is_empty()
+            && result.release().group.is_some_and(|group| self.blocked_groups.contains(&group.to_lowercase()))
+        {
+            return false;
+        }
+
+        if self.hide_suspicious && !suspicions(result).is_empty() {
+            return false;
+        }
+
+        if self.required.is_empty() && self.excluded.is_empty() {
+            return true;
+        }
+
+        let normalized = normalize(&result.title);
+        self.required.iter().all(|term| term.matches(&result.title, &normalized))
+            && !self.excluded.iter().any(|term| term.matches(&result.title, &normalized))
+    }
+
+    /// Ids of the selected indexers, with categories expanded and unknown
+    /// names kept as they are, or `None` when "all" is selected
+    fn selected_indexers(&self) -> Option<Vec<&str>> {
+        if self.indexers.iter().any(|i| i == "all") {
+            return None;
+        }
+        let mut selected = Vec::new();
+        for name in &self.indexers {
+            let resolved = self.registry.resolve(name);
+            if resolved.is_empty() {
+                selected.push(name.as_str());
+            }
+            selected.extend(resolved.into_iter().map(|indexer| indexer.id.as_str()));
+        }
+        Some(selected)
+    }
+
+    /// Whether the result, or any copy merged into it, comes from one of
+    /// the selected indexers
+    fn includes_indexer(&self, result: &TorrentResult, selected: &[&str]) -> bool {
+        let copies = if result.sources.is_empty() { std::slice::from_ref(result) } else { &result.sources[..] };
+        copies.iter().any(|copy| {
+            let id = self.registry.api_id(&copy.indexer);
+            selected.iter().any(|selected| selected.eq_ignore_ascii_case(id))
+        })
+    }
+
+    /// Whether the result, or any copy merged into it, passes the filters
+    /// configured for the indexer it came from
+    fn passes_indexer_filters(&self, result: &TorrentResult) -> bool {
+        let copies = if result.sources.is_empty() { std::slice::from_ref(result) } else { &result.sources[..] };
+        copies.iter().any(|copy| self.registry.allows(&copy.indexer, copy))
+    }
+
+    /// Drop results that don't match and sort the rest
+    pub fn apply(&self, results: &mut Vec<TorrentResult>) {
+        let selected = self.selected_indexers();
+        results.retain(|r| self.matches(r, selected.as_deref()));
+        self.sort.sort(results, &self.registry);
+    }
+}
+
+/// A required or excluded title term: whole words or a phrase (any case,
+/// separators ignored), or a case-insensitive `/regex/` on the raw title
+#[derive(Debug, Clone)]
+pub enum TitleTerm {
+    Words(String),
+    Regex(Regex),
+}
+
+impl TitleTerm {
+    /// `/.../` becomes a regex if it compiles; anything else is matched as words
+    pub fn new(text: &str) -> Self {
+        let pattern = text.strip_prefix('/').and_then(|t| t.strip_suffix('/')).filter(|p| !p.is_empty());
+        match pattern.and_then(|p| RegexBuilder::new(p).case_insensitive(true).build().ok()) {
+            Some(regex) => TitleTerm::Regex(regex),
+            None => TitleTerm::Words(normalize(text)),
+        }
+    }
+
+    /// Whether `title` (and its `normalize`d form) contains the term
+    pub fn matches(&self, title: &str, normalized: &str) -> bool {
+        match self {
+            TitleTerm::Words(words) => contains_words(normalized, words),
+            TitleTerm::Regex(regex) => regex.is_match(title),
+        }
+    }
+}
+
+impl PartialEq for TitleTerm {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TitleTerm::Words(a), TitleTerm::Words(b)) => a == b,
+            (TitleTerm::Regex(a), TitleTerm::Regex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+/// Lowercase and turn scene separators (dots, underscores, brackets, ...)
+/// into single spaces, so "Dune.Part.Two" matches the phrase "part two"
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whole-word match of a normalized needle in a normalized haystack, so
+/// "cam" doesn't match "camelot"
+pub fn contains_words(haystack: &str, needle: &str) -> bool {
+    if needle.is_empty() {
+        return true;
+    }
+    haystack.match_indices(needle).any(|(start, _)| {
+        let end = start + needle.len();
+        (start == 0 || haystack.as_bytes()[start - 1] == b' ')
+            && (end == haystack.len() || haystack.as_bytes()[end] == b' ')
+    })
+}
+
+/// Parse a human size such as "700M", "4.5G" or "1.2 GiB" into bytes.
+/// Units are binary (1G = 1024³ bytes) to match how sizes are displayed;
+/// a bare number is taken as bytes.
+pub fn parse_size(input: &str) -> Option<u64> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: u64 = match unit.trim().to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        _ => return None,
+    };
+
+    Some((number * multiplier as f64) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sizes_in_binary_units() {
+        assert_eq!(parse_size("700M"), Some(700 << 20));
+        assert_eq!(parse_size("4.5G"), Some((4.5 * (1u64 << 30) as f64) as u64));
+        assert_eq!(parse_size("1.2 GiB"), Some((1.2 * (1u64 << 30) as f64) as u64));
+        assert_eq!(parse_size("2tb"), Some(2 << 40));
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size(" 10 kb "), Some(10 << 10));
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        assert_eq!(parse_size(""), None);
+        assert_eq!(parse_size("G"), None);
+        assert_eq!(parse_size("10 parsecs"), None);
+        assert_eq!(parse_size("1.2.3G"), None);
+    }
+
+    fn result(title: &str, indexer: &str) -> TorrentResult {
+        TorrentResult { indexer: indexer.to_string(), ..TorrentResult::sample(title) }
+    }
+
+    #[test]
+    fn selects_indexers_by_name_alias_and_category() {
+        let mut results = vec![
+            result("a", "The Pirate Bay"),
+            result("b", "RuTracker.org"),
+            result("c", "yts"),
+            result("d", "somewhere"),
+        ];
+        let filters = Filters { indexers: vec!["TPB".to_string(), "movies".to_string()], ..Filters::default() };
+        filters.apply(&mut results);
+
+        let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, ["a", "c"]);
+    }
+}
@@ -0,0 +1,72 @@
+/// Case-insensitive fuzzy match of `pattern` against `text`.
+///
+/// Every whitespace-separated word of the pattern must appear in the text,
+/// either as a substring or, failing that, as a subsequence ("hdr" matches
+/// "HDR10" and "H.D.R"). Returns the char positions in `text` that matched,
+/// sorted, or `None` if some word doesn't match.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<Vec<usize>> {
+    let text: Vec<char> = text.chars().map(lower).collect();
+    let mut positions = Vec::new();
+
+    for word in pattern.split_whitespace() {
+        let word: Vec<char> = word.chars().map(lower).collect();
+        positions.extend(find_substring(&text, &word).or_else(|| find_subsequence(&text, &word))?);
+    }
+
+    positions.sort_unstable();
+    positions.dedup();
+    Some(positions)
+}
+
+fn find_substring(text: &[char], word: &[char]) -> Option<Vec<usize>> {
+    text.windows(word.len())
+        .position(|window| window == word)
+        .map(|start| (start..start + word.len()).collect())
+}
+
+fn find_subsequence(text: &[char], word: &[char]) -> Option<Vec<usize>> {
+    let mut positions = Vec::with_capacity(word.len());
+    let mut chars = text.iter().enumerate();
+
+    for c in word {
+        let (index, _) = chars.by_ref().find(|(_, t)| *t == c)?;
+        positions.push(index);
+    }
+    Some(positions)
+}
+
+/// One-to-one lowercase so positions line up with the original text
+fn lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_every_word_as_a_substring_or_subsequence() {
+        assert!(fuzzy_match("dune 1080p", "Dune.Part.Two.2024.1080p.WEB-DL").is_some());
+        assert!(fuzzy_match("hdr", "Movie.H.D.R.2160p").is_some());
+        assert!(fuzzy_match("dune 720p", "Dune.Part.Two.2024.1080p").is_none());
+        assert!(fuzzy_match("rdh", "Movie.HDR").is_none());
+    }
+
+    #[test]
+    fn ignores_case_on_both_sides() {
+        assert_eq!(fuzzy_match("DUNE", "dune"), Some(vec![0, 1, 2, 3]));
+        assert_eq!(fuzzy_match("dune", "DUNE"), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn returns_sorted_unique_char_positions() {
+        // A substring wins over an earlier subsequence
+        assert_eq!(fuzzy_match("hdr", "H.D.R HDR"), Some(vec![6, 7, 8]));
+        assert_eq!(fuzzy_match("hdr", "H.D.R"), Some(vec![0, 2, 4]));
+        assert_eq!(fuzzy_match("two dune", "Dune Two"), Some(vec![0, 1, 2, 3, 5, 6, 7]));
+        assert_eq!(fuzzy_match("dune du", "Dune"), Some(vec![0, 1, 2, 3]));
+        // Positions count chars, not bytes
+        assert_eq!(fuzzy_match("amelie", "Amélie Amelie"), Some(vec![7, 8, 9, 10, 11, 12]));
+        assert_eq!(fuzzy_match("", "anything"), Some(vec![]));
+    }
+}
@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+
+use super::filters::parse_size;
+use super::TorrentResult;
+
+/// What an indexer mostly carries; selecting a category by name (`-i anime`,
+/// `idx:tv`) searches every enabled indexer in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexerCategory {
+    #[default]
+    General,
+    Movies,
+    Tv,
+    Anime,
+}
+
+impl IndexerCategory {
+    pub const ALL: [IndexerCategory; 4] = [
+        IndexerCategory::General,
+        IndexerCategory::Movies,
+        IndexerCategory::Tv,
+        IndexerCategory::Anime,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            IndexerCategory::General => "general",
+            IndexerCategory::Movies => "movies",
+            IndexerCategory::Tv => "tv",
+            IndexerCategory::Anime => "anime",
+        }
+    }
+}
+
+/// An indexer chill.institute searches
+#[derive(Debug, Clone, PartialEq)]
+pub struct Indexer {
+    /// Short name shown in the table and the Filters panel
+    pub name: String,
+    /// What the API expects in `indexer=`
+    pub id: String,
+    pub category: IndexerCategory,
+    /// Disabled indexers are left out of the Filters panel and of "all"
+    pub enabled: bool,
+    /// Other names the API reports results under, e.g. "The Pirate Bay"
+    pub aliases: Vec<String>,
+    pub filters: IndexerFilters,
+}
+
+/// Filters applied to this indexer's results on top of the panel's, e.g. a
+/// higher seeder minimum for an indexer with many dead torrents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexerFilters {
+    pub min_seeds: Option<u32>,
+    /// Sizes like "700M" or "4.5G"
+    pub min_size: Option<String>,
+    pub max_size: Option<String>,
+}
+
+impl IndexerFilters {
+    fn allows(&self, result: &TorrentResult) -> bool {
+        self.min_seeds.is_none_or(|min| result.seeders >= min)
+            && self.min_size.as_deref().and_then(parse_size).is_none_or(|min| result.size >= min)
+            && self.max_size.as_deref().and_then(parse_size).is_none_or(|max| result.size <= max)
+    }
+}
+
+/// An indexer as written in the config's `indexers` list. An entry whose
+/// id or name matches a built-in indexer changes only the fields it sets,
+/// so `{"id": "yts", "enabled": false}` hides YTS and
+/// `{"name": "RARBG", "id": "rarbg"}` follows an API rename. Any other
+/// entry adds a new indexer and needs at least an id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexerEntry {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub category: Option<IndexerCategory>,
+    pub enabled: Option<bool>,
+    pub aliases: Option<Vec<String>>,
+    pub filters: Option<IndexerFilters>,
+}
+
+/// Every indexer ChillTUI knows, in Filters panel order. Built once from
+/// the config and shared by the UI, the CLI and the filters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexerRegistry {
+    indexers: Vec<Indexer>,
+}
+
+impl Default for IndexerRegistry {
+    /// The indexers chill.institute offered when this version was released
+    fn default() -> Self {
+        let builtin = |name: &str, id: &str, category, aliases: &[&str]| Indexer {
+            name: name.to_string(),
+            id: id.to_string(),
+            category,
+            enabled: true,
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            filters: IndexerFilters::default(),
+        };
+        Self {
+            indexers: vec![
+                builtin("1337x", "1337x", IndexerCategory::General, &[]),
+                builtin("EZTV", "eztv", IndexerCategory::Tv, &[]),
+                builtin("nyaa.si", "nyaa.si", IndexerCategory::Anime, &[]),
+                builtin("RUtracker", "rutracker", IndexerCategory::General, &["RuTracker.org"]),
+                builtin("TPB", "thepiratebay", IndexerCategory::General, &["The Pirate Bay"]),
+                builtin("RARBG", "therarbg", IndexerCategory::General, &[]),
+                builtin("Uindex", "Uindex", IndexerCategory::General, &[]),
+                builtin("YTS", "yts", IndexerCategory::Movies, &[]),
+            ],
+        }
+    }
+}
+
+impl IndexerRegistry {
+    /// The built-in indexers with the config's entries applied
+    pub fn from_entries(entries: &[IndexerEntry]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut registry = Self::default();
+        for entry in entries {
+            let existing = registry.indexers.iter().position(|indexer| {
+                entry.id.as_deref().is_some_and(|id| indexer.id.eq_ignore_ascii_case(id))
+                    || entry.name.as_deref().is_some_and(|name| indexer.name.eq_ignore_ascii_case(name))
+            });
+
+            let indexer = match existing {
+                Some(i) => &mut registry.indexers[i],
+                None => {
+                    let id = entry.id.clone().ok_or_else(|| {
+                        format!("indexer '{}' needs an id", entry.name.as_deref().unwrap_or("?"))
+                    })?;
+                    registry.indexers.push(Indexer {
+                        name: id.clone(),
+                        id,
+                        category: IndexerCategory::default(),
+                        enabled: true,
+                        aliases: Vec::new(),
+                        filters: IndexerFilters::default(),
+                    });
+                    registry.indexers.last_mut().expect("just pushed")
+                }
+            };
+
+            if let Some(id) = &entry.id {
+                indexer.id = id.clone();
+            }
+            if let Some(name) = &entry.name {
+                indexer.name = name.clone();
+            }
+            if let Some(category) = entry.category {
+                indexer.category = category;
+            }
+            if let Some(enabled) = entry.enabled {
+                indexer.enabled = enabled;
+            }
+            if let Some(aliases) = &entry.aliases {
+                indexer.aliases = aliases.clone();
+            }
+            if let Some(filters) = &entry.filters {
+                indexer.filters = filters.clone();
+            }
+        }
+
+        if let Some(name) = registry.indexers.iter().map(|i| &i.name).find(|name| name.eq_ignore_ascii_case("all")) {
+            return Err(format!("'{}' is reserved and can't name an indexer", name).into());
+        }
+        Ok(registry)
+    }
+
+    pub fn all(&self) -> &[Indexer] {
+        &self.indexers
+    }
+
+    pub fn enabled(&self) -> impl Iterator<Item = &Indexer> {
+        self.indexers.iter().filter(|indexer| indexer.enabled)
+    }
+
+    /// The indexer called `name` (display name, API id or alias, any case)
+    pub fn find(&self, name: &str) -> Option<&Indexer> {
+        self.indexers.iter().find(|indexer| {
+            indexer.name.eq_ignore_ascii_case(name)
+                || indexer.id.eq_ignore_ascii_case(name)
+                || indexer.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// The id the chill.institute API expects for an indexer's name
+    pub fn api_id<'a>(&'a self, name: &'a str) -> &'a str {
+        self.find(name).map_or(name, |indexer| &indexer.id)
+    }
+
+    /// The short display name of an indexer as the API reports it
+    pub fn display_name<'a>(&'a self, source: &'a str) -> &'a str {
+        self.find(source).map_or(source, |indexer| &indexer.name)
+    }
+
+    /// The indexers `name` selects: one indexer, or every enabled indexer
+    /// of a category
+    pub fn resolve(&self, name: &str) -> Vec<&Indexer> {
+        if let Some(indexer) = self.find(name) {
+            return vec![indexer];
+        }
+        match IndexerCategory::ALL.iter().find(|category| category.label().eq_ignore_ascii_case(name)) {
+            Some(category) => self.enabled().filter(|indexer| indexer.category == *category).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// API ids to request for `names`. "all" (or nothing) is every enabled
+    /// indexer, sent as no ids at all unless some are disabled; unknown
+    /// names are passed to the API as they are. A category without enabled
+    /// indexers is an error rather than a search of everything.
+    pub fn api_ids(&self, names: &[String]) -> Result<Vec<String>, String> {
+        if names.is_empty() || names.iter().any(|name| name.eq_ignore_ascii_case("all")) {
+            if self.indexers.iter().all(|indexer| indexer.enabled) {
+                return Ok(Vec::new());
+            }
+            return Ok(self.enabled().map(|indexer| indexer.id.clone()).collect());
+        }
+
+        let mut ids: Vec<String> = Vec::new();
+        for name in names {
+            let resolved = self.resolve(name);
+            if resolved.is_empty() {
+                if IndexerCategory::ALL.iter().any(|category| category.label().eq_ignore_ascii_case(name)) {
+                    return Err(format!("no enabled indexer in category '{}'", name));
+                }
+                ids.push(name.clone());
+            }
+            ids.extend(resolved.into_iter().map(|indexer| indexer.id.clone()));
+        }
+        let mut seen = Vec::new();
+        ids.retain(|id| {
+            let new = !seen.contains(id);
+            seen.push(id.clone());
+            new
+        });
+        Ok(ids)
+    }
+
+    /// Whether a result reported under `source` passes the filters of the
+    /// indexer it came from
+    pub fn allows(&self, source: &str, result: &TorrentResult) -> bool {
+        self.find(source).is_none_or(|indexer| indexer.filters.allows(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(json: &str) -> IndexerRegistry {
+        let entries: Vec<IndexerEntry> = serde_json::from_str(json).expect("valid entries");
+        IndexerRegistry::from_entries(&entries).expect("valid registry")
+    }
+
+    fn names<'a>(indexers: impl Iterator<Item = &'a Indexer>) -> Vec<&'a str> {
+        indexers.map(|indexer| indexer.name.as_str()).collect()
+    }
+
+    #[test]
+    fn leaves_disabled_indexers_out_of_enabled() {
+        let registry = registry(r#"[{"id": "yts", "enabled": false}, {"name": "eztv", "enabled": false}]"#);
+
+        assert_eq!(registry.all().len(), IndexerRegistry::default().all().len());
+        assert!(!names(registry.enabled()).iter().any(|name| *name == "YTS" || *name == "EZTV"));
+        assert_eq!(registry.api_ids(&["all".into()]).unwrap().len(), registry.all().len() - 2);
+        assert!(registry.api_ids(&["movies".into()]).is_err());
+        // Named directly, a disabled indexer is still searched
+        assert_eq!(registry.api_ids(&["yts".into()]).unwrap(), ["yts"]);
+    }
+
+    #[test]
+    fn maps_display_names_to_api_ids_and_back() {
+        let registry = registry(
+            r#"[
+                {"name": "RARBG", "id": "rarbg"},
+                {"id": "knaben", "name": "Knaben", "aliases": ["Knaben Database"]}
+            ]"#,
+        );
+
+        assert_eq!(registry.api_id("rarbg"), "rarbg");
+        assert_eq!(registry.api_id("tpb"), "thepiratebay");
+        assert_eq!(registry.api_id("Knaben"), "knaben");
+        assert_eq!(registry.api_id("unknown"), "unknown");
+        assert_eq!(registry.display_name("The Pirate Bay"), "TPB");
+        assert_eq!(registry.display_name("Knaben Database"), "Knaben");
+        assert_eq!(registry.display_name("unknown"), "unknown");
+        assert_eq!(registry.find("therarbg"), None);
+    }
+
+    #[test]
+    fn rejects_new_indexers_without_an_id_or_named_all() {
+        let entries = |json| serde_json::from_str::<Vec<IndexerEntry>>(json).unwrap();
+        assert!(IndexerRegistry::from_entries(&entries(r#"[{"name": "Knaben"}]"#)).is_err());
+        assert!(IndexerRegistry::from_entries(&entries(r#"[{"id": "all"}]"#)).is_err());
+    }
+
+    #[test]
+    fn applies_each_indexers_own_seed_and_size_gates() {
+        let registry = registry(
+            r#"[{"id": "1337x", "filters": {"min_seeds": 5, "min_size": "500M", "max_size": "4G"}}]"#,
+        );
+        let result = |seeders, size| TorrentResult { seeders, size, ..TorrentResult::sample("Dune") };
+
+        assert!(registry.allows("1337x", &result(5, 1 << 30)));
+        assert!(!registry.allows("1337x", &result(4, 1 << 30)));
+        assert!(!registry.allows("1337x", &result(50, 100 << 20)));
+        assert!(!registry.allows("1337x", &result(50, 5 << 30)));
+        // Other and unknown indexers keep everything
+        assert!(registry.allows("yts", &result(0, 1)));
+        assert!(registry.allows("unknown", &result(0, 1)));
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed `magnet:` URI.
+///
+/// Infohashes are normalized to lowercase hex, so two links for the same
+/// torrent compare equal regardless of encoding or parameter order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Magnet {
+    /// BitTorrent v1 infohash (40 hex chars), from `xt=urn:btih:`
+    pub btih: Option<String>,
+    /// BitTorrent v2 multihash (hex), from `xt=urn:btmh:`
+    pub btmh: Option<String>,
+    /// Display name (`dn`)
+    pub name: Option<String>,
+    /// Exact length in bytes (`xl`)
+    pub length: Option<u64>,
+    /// Tracker URLs (`tr`), in order and without duplicates
+    pub trackers: Vec<String>,
+}
+
+impl Magnet {
+    /// Identity of the torrent: the v1 infohash, or the v2 multihash for
+    /// v2-only torrents. Parsed magnets always have one; a hand-built one
+    /// may not.
+    pub fn infohash(&self) -> Option<&str> {
+        self.btih.as_deref().or(self.btmh.as_deref())
+    }
+}
+
+impl FromStr for Magnet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let query = s.get(..8)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("magnet:?"))
+            .map(|_| &s[8..])
+            .ok_or_else(|| "not a magnet link".to_string())?;
+
+        let mut magnet = Magnet { btih: None, btmh: None, name: None, length: None, trackers: Vec::new() };
+
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value);
+            // Numbered keys like `xt.1` or `tr.2` are equivalent to the plain ones
+            let key = key.split('.').next().unwrap_or(key).to_ascii_lowercase();
+
+            match key.as_str() {
+                "xt" => {
+                    let lower = value.to_ascii_lowercase();
+                    if let Some(hash) = lower.strip_prefix("urn:btih:") {
+                        if magnet.btih.is_none() {
+                            magnet.btih = Some(parse_btih(hash)?);
+                        }
+                    } else if let Some(hash) = lower.strip_prefix("urn:btmh:") {
+                        if magnet.btmh.is_none() {
+                            magnet.btmh = Some(parse_btmh(hash)?);
+                        }
+                    }
+                }
+                "dn" if magnet.name.is_none() && !value.is_empty() => magnet.name = Some(value),
+                "xl" => magnet.length = value.parse().ok(),
+                "tr" if !value.is_empty() && !magnet.trackers.contains(&value) => magnet.trackers.push(value),
+                _ => {}
+            }
+        }
+
+        if magnet.btih.is_none() && magnet.btmh.is_none() {
+            return Err("magnet link has no BitTorrent infohash".to_string());
+        }
+        Ok(magnet)
+    }
+}
+
+/// Canonical form: `xt` first, then `dn`, `xl` and the trackers
+impl fmt::Display for Magnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params = Vec::new();
+        if let Some(btih) = &self.btih {
+            params.push(format!("xt=urn:btih:{}", btih));
+        }
+        if let Some(btmh) = &self.btmh {
+            params.push(format!("xt=urn:btmh:{}", btmh));
+        }
+        if let Some(name) = &self.name {
+            params.push(format!("dn={}", percent_encode(name)));
+        }
+        if let Some(length) = self.length {
+            params.push(format!("xl={}", length));
+        }
+        params.extend(self.trackers.iter().map(|tr| format!("tr={}", percent_encode(tr))));
+
+        write!(f, "magnet:?{}", params.join("&"))
+    }
+}
+
+/// v1 infohash as 40 hex chars or 32 base32 chars
+fn parse_btih(hash: &str) -> Result<String, String> {
+    match hash.len() {
+        40 if hash.bytes().all(|b| b.is_ascii_hexdigit()) => Ok(hash.to_string()),
+        32 => base32_decode(hash)
+            .map(|bytes| to_hex(&bytes))
+            .ok_or_else(|| format!("invalid base32 infohash '{}'", hash)),
+        _ => Err(format!("invalid infohash '{}'", hash)),
+    }
+}
+
+/// v2 multihash: sha2-256 (`12`), 32-byte digest (`20`), as hex
+fn parse_btmh(hash: &str) -> Result<String, String> {
+    if hash.len() == 68 && hash.starts_with("1220") && hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Ok(hash.to_string())
+    } else {
+        Err(format!("invalid v2 multihash '{}'", hash))
+    }
+}
+
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in input.bytes() {
+        let value = match c {
+            b'a'..=b'z' => c - b'a',
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | u32::from(value);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                match (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                    (Some(high), Some(low)) => {
+                        decoded.push(high << 4 | low);
+                        i += 3;
+                        continue;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            b'+' => decoded.push(b' '),
+            b => decoded.push(b),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|d| d as u8)
+}
+
+fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for b in input.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(b as char),
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "c12fe1c06bba254a9dc9f519b335aa7c1367a88a";
+
+    #[test]
+    fn decodes_a_base32_infohash_to_hex() {
+        let magnet: Magnet = "magnet:?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKEK".parse().unwrap();
+        assert_eq!(magnet.infohash(), Some(HEX));
+    }
+
+    #[test]
+    fn hex_infohashes_are_lowercased() {
+        let magnet: Magnet = format!("magnet:?xt=urn:btih:{}", HEX.to_uppercase()).parse().unwrap();
+        assert_eq!(magnet.infohash(), Some(HEX));
+    }
+
+    #[test]
+    fn rejects_bad_infohashes() {
+        assert!("magnet:?xt=urn:btih:1234".parse::<Magnet>().is_err());
+        assert!("magnet:?xt=urn:btih:YEX6DQDLXISUVHOJ6UM3GNNKPQJWPKE1".parse::<Magnet>().is_err());
+        assert!("magnet:?dn=no+hash".parse::<Magnet>().is_err());
+        assert!("http://example.com/?xt=urn:btih:c12fe1c06bba254a9dc9f519b335aa7c1367a88a".parse::<Magnet>().is_err());
+    }
+
+    #[test]
+    fn accepts_the_scheme_in_any_case() {
+        for scheme in ["magnet:?", "MAGNET:?", "Magnet:?"] {
+            let magnet: Magnet = format!("{}xt=urn:btih:{}", scheme, HEX).parse().unwrap();
+            assert_eq!(magnet.infohash(), Some(HEX));
+        }
+    }
+
+    #[test]
+    fn prints_the_canonical_form() {
+        let magnet: Magnet = format!(
+            "magnet:?tr=udp%3A%2F%2Ftracker.example%3A1337&dn=Some+Movie&xl=1024&tr.1=udp%3A%2F%2Ftracker.example%3A1337&xt=urn:btih:{}",
+            HEX.to_uppercase(),
+        ).parse().unwrap();
+        assert_eq!(magnet.name.as_deref(), Some("Some Movie"));
+        assert_eq!(magnet.trackers, vec!["udp://tracker.example:1337".to_string()]);
+        assert_eq!(
+            magnet.to_string(),
+            format!("magnet:?xt=urn:btih:{}&dn=Some%20Movie&xl=1024&tr=udp%3A%2F%2Ftracker.example%3A1337", HEX),
+        );
+        assert_eq!(magnet.to_string().parse::<Magnet>().unwrap(), magnet);
+    }
+
+    #[test]
+    fn hand_built_magnets_without_a_hash_have_no_infohash() {
+        let magnet = Magnet { btih: None, btmh: None, name: None, length: None, trackers: Vec::new() };
+        assert_eq!(magnet.infohash(), None);
+    }
+}
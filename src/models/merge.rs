@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use super::{Magnet, TorrentResult};
+
+/// Collapse results that share an infohash into one entry per torrent.
+///
+/// The merged entry takes the title and indexer of its best-seeded copy,
+/// the highest seeder and leecher counts, and a magnet carrying every
+/// tracker from every copy. The individual copies are kept in `sources`,
+/// best first. Results without a parseable infohash are left alone, and
+/// the order of first appearance is preserved.
+pub fn merge_duplicates(results: Vec<TorrentResult>) -> Vec<TorrentResult> {
+    let mut groups: Vec<Vec<TorrentResult>> = Vec::new();
+    let mut by_hash: HashMap<String, usize> = HashMap::new();
+
+    for result in results {
+        match result.infohash() {
+            Some(hash) => match by_hash.get(&hash) {
+                Some(&group) => groups[group].push(result),
+                None => {
+                    by_hash.insert(hash, groups.len());
+                    groups.push(vec![result]);
+                }
+            },
+            None => groups.push(vec![result]),
+        }
+    }
+
+    groups.into_iter().map(merge_group).collect()
+}
+
+fn merge_group(mut copies: Vec<TorrentResult>) -> TorrentResult {
+    if copies.len() == 1 {
+        return copies.remove(0);
+    }
+
+    copies.sort_by(|a, b| b.seeders.cmp(&a.seeders).then(b.leechers.cmp(&a.leechers)));
+
+    let mut merged = copies[0].clone();
+    merged.leechers = copies.iter().map(|c| c.leechers).max().unwrap_or(0);
+
+    if let Some(mut magnet) = merged.parsed_magnet() {
+        for copy in &copies[1..] {
+            let Ok(other) = copy.magnet.parse::<Magnet>() else { continue };
+            for tracker in other.trackers {
+                if !magnet.trackers.contains(&tracker) {
+                    magnet.trackers.push(tracker);
+                }
+            }
+            if magnet.btmh.is_none() {
+                magnet.btmh = other.btmh;
+            }
+        }
+        merged.magnet = magnet.to_string();
+    }
+
+    merged.sources = copies;
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH_A: &str = "c12fe1c06bba254a9dc9f519b335aa7c1367a88a";
+    const HASH_B: &str = "0000000000000000000000000000000000000001";
+
+    fn copy(hash: &str, indexer: &str, seeders: u32, leechers: u32, tracker: &str) -> TorrentResult {
+        TorrentResult {
+            indexer: indexer.to_string(),
+            seeders,
+            leechers,
+            magnet: format!("magnet:?xt=urn:btih:{}&tr={}", hash, tracker),
+            ..TorrentResult::sample(&format!("Dune from {}", indexer))
+        }
+    }
+
+    #[test]
+    fn merges_copies_of_the_same_torrent() {
+        let merged = merge_duplicates(vec![
+            copy(HASH_A, "eztv", 20, 9, "udp%3A%2F%2Fone"),
+            copy(&HASH_A.to_uppercase(), "yts", 50, 3, "udp%3A%2F%2Ftwo"),
+        ]);
+
+        assert_eq!(merged.len(), 1);
+        let merged = &merged[0];
+        assert_eq!(merged.indexer, "yts");
+        assert_eq!(merged.seeders, 50);
+        assert_eq!(merged.leechers, 9);
+        assert_eq!(merged.sources.iter().map(|s| s.indexer.as_str()).collect::<Vec<_>>(), ["yts", "eztv"]);
+
+        let magnet = merged.parsed_magnet().expect("merged magnet parses");
+        assert_eq!(magnet.trackers, ["udp://two", "udp://one"]);
+    }
+
+    #[test]
+    fn keeps_different_torrents_apart_in_order() {
+        let merged = merge_duplicates(vec![
+            copy(HASH_B, "eztv", 5, 1, "udp%3A%2F%2Fone"),
+            copy(HASH_A, "yts", 50, 3, "udp%3A%2F%2Fone"),
+        ]);
+
+        assert_eq!(merged.iter().map(|r| r.indexer.as_str()).collect::<Vec<_>>(), ["eztv", "yts"]);
+        assert!(merged.iter().all(|r| r.sources.is_empty()));
+    }
+
+    #[test]
+    fn leaves_results_without_an_infohash_alone() {
+        let broken = TorrentResult { magnet: "magnet:?dn=Dune".to_string(), ..TorrentResult::sample("Dune") };
+        let merged = merge_duplicates(vec![broken.clone(), broken]);
+
+        assert_eq!(merged.len(), 2);
+        assert!(merged.iter().all(|r| r.sources.is_empty() && r.magnet == "magnet:?dn=Dune"));
+    }
+}
@@ -1,4 +1,191 @@
+pub mod blocklist;
+pub mod filters;
+pub mod fuzzy;
+pub mod indexer;
+pub mod magnet;
+pub mod merge;
+pub mod profile;
+pub mod query;
+pub mod release;
+pub mod rules;
+pub mod series;
+pub mod suspicious;
+
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+pub use blocklist::Blocklist;
+pub use filters::Filters;
+pub use fuzzy::fuzzy_match;
+pub use indexer::{IndexerEntry, IndexerRegistry};
+pub use magnet::Magnet;
+pub use merge::merge_duplicates;
+pub use profile::QualityProfile;
+pub use query::ParsedQuery;
+pub use release::ReleaseInfo;
+pub use rules::AcceptRules;
+pub use suspicious::{looks_unsafe, suspicions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortMode {
+    Seeders,
+    Leechers,
+    Ratio,
+    Size,
+    Name,
+    Indexer,
+    Resolution,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 7] = [
+        SortMode::Seeders,
+        SortMode::Leechers,
+        SortMode::Ratio,
+        SortMode::Size,
+        SortMode::Name,
+        SortMode::Indexer,
+        SortMode::Resolution,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortMode::Seeders => "Seeders",
+            SortMode::Leechers => "Leechers",
+            SortMode::Ratio => "Ratio",
+            SortMode::Size => "Size",
+            SortMode::Name => "Name",
+            SortMode::Indexer => "Indexer",
+            SortMode::Resolution => "Resolution",
+        }
+    }
+
+    /// Numbers sort biggest first, text sorts A-Z
+    pub fn descending_by_default(&self) -> bool {
+        !matches!(self, SortMode::Name | SortMode::Indexer)
+    }
+
+    /// The mode after this one, wrapping around
+    pub fn next(&self) -> SortMode {
+        let index = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Ascending comparison on this key
+    fn compare(&self, a: &SortEntry, b: &SortEntry) -> Ordering {
+        let (a_indexer, b_indexer) = (&a.indexer, &b.indexer);
+        let (a_resolution, b_resolution) = (a.resolution, b.resolution);
+        let (a, b) = (&a.result, &b.result);
+        match self {
+            SortMode::Seeders => a.seeders.cmp(&b.seeders),
+            SortMode::Leechers => a.leechers.cmp(&b.leechers),
+            // seeders / leechers, cross-multiplied to stay in integers
+            SortMode::Ratio => {
+                let ratio = |r: &TorrentResult, other: &TorrentResult| u64::from(r.seeders) * u64::from(other.leechers.max(1));
+                ratio(a, b).cmp(&ratio(b, a))
+            }
+            SortMode::Size => a.size.cmp(&b.size),
+            SortMode::Name => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortMode::Indexer => a_indexer.cmp(b_indexer),
+            SortMode::Resolution => a_resolution.cmp(&b_resolution),
+        }
+    }
+
+    /// Comparison in this key's default direction
+    fn compare_default(&self, a: &SortEntry, b: &SortEntry) -> Ordering {
+        let ordering = self.compare(a, b);
+        if self.descending_by_default() { ordering.reverse() } else { ordering }
+    }
+}
+
+impl std::str::FromStr for SortMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "seeders" | "seeds" => Ok(SortMode::Seeders),
+            "leechers" | "peers" => Ok(SortMode::Leechers),
+            "ratio" => Ok(SortMode::Ratio),
+            "size" => Ok(SortMode::Size),
+            "name" | "title" => Ok(SortMode::Name),
+            "indexer" | "source" => Ok(SortMode::Indexer),
+            "resolution" | "res" => Ok(SortMode::Resolution),
+            _ => Err(format!(
+                "unknown sort mode '{}' (expected seeders, leechers, ratio, size, name, indexer or resolution)",
+                s
+            )),
+        }
+    }
+}
+
+/// Sort key with its direction, plus an optional tie-breaker that always
+/// uses its own default direction
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SortOrder {
+    pub key: SortMode,
+    pub descending: bool,
+    #[serde(default)]
+    pub then_by: Option<SortMode>,
+}
+
+impl SortOrder {
+    pub fn new(key: SortMode) -> Self {
+        Self { key, descending: key.descending_by_default(), then_by: None }
+    }
+
+    pub fn sort(&self, results: &mut Vec<TorrentResult>, registry: &IndexerRegistry) {
+        let uses = |mode| self.key == mode || self.then_by == Some(mode);
+        let (by_indexer, by_resolution) = (uses(SortMode::Indexer), uses(SortMode::Resolution));
+        let mut entries: Vec<SortEntry> = results.drain(..)
+            .map(|result| SortEntry {
+                indexer: if by_indexer { registry.display_name(&result.indexer).to_lowercase() } else { String::new() },
+                resolution: if by_resolution { result.release().resolution } else { None },
+                result,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            let primary = self.key.compare(a, b);
+            let primary = if self.descending { primary.reverse() } else { primary };
+            primary.then_with(|| self.then_by.map_or(Ordering::Equal, |key| key.compare_default(a, b)))
+        });
+        results.extend(entries.into_iter().map(|entry| entry.result));
+    }
+}
+
+/// A result being sorted, with its indexer's lowercase display name and its
+/// resolution worked out once rather than on every comparison
+struct SortEntry {
+    indexer: String,
+    resolution: Option<u16>,
+    result: TorrentResult,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::new(SortMode::Seeders)
+    }
+}
+
+/// `KEY`, `KEY:asc` or `KEY:desc`
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, direction) = match s.split_once(':') {
+            Some((key, direction)) => (key, Some(direction)),
+            None => (s, None),
+        };
+        let mut order = SortOrder::new(key.parse()?);
+        match direction.map(str::to_lowercase).as_deref() {
+            None => {}
+            Some("asc") => order.descending = false,
+            Some("desc") => order.descending = true,
+            Some(other) => return Err(format!("unknown sort direction '{}' (expected asc or desc)", other)),
+        }
+        Ok(order)
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TorrentResult {
@@ -13,15 +200,46 @@ pub struct TorrentResult {
     pub magnet: String,
     #[serde(skip)]
     pub selected: bool,
+    /// Per-indexer copies folded into this result by `merge_duplicates`
+    #[serde(skip)]
+    pub sources: Vec<TorrentResult>,
 }
 
 impl TorrentResult {
     pub fn size_str(&self) -> String {
+        Self::format_size(self.size)
+    }
+
+    /// Resolution, source, codec etc. parsed from the title
+    pub fn release(&self) -> ReleaseInfo {
+        ReleaseInfo::parse(&self.title)
+    }
+
+    /// Display names of every indexer this result was found on
+    pub fn indexer_names<'a>(&'a self, registry: &'a IndexerRegistry) -> Vec<&'a str> {
+        if self.sources.is_empty() {
+            vec![registry.display_name(&self.indexer)]
+        } else {
+            self.sources.iter().map(|s| registry.display_name(&s.indexer)).collect()
+        }
+    }
+
+    /// Parsed magnet link, or `None` if it isn't a valid BitTorrent magnet
+    pub fn parsed_magnet(&self) -> Option<Magnet> {
+        self.magnet.parse().ok()
+    }
+
+    /// Normalized (lowercase hex) infohash identifying this torrent
+    pub fn infohash(&self) -> Option<String> {
+        self.parsed_magnet().and_then(|m| m.infohash().map(str::to_string))
+    }
+
+    pub fn format_size(bytes: u64) -> String {
         const KIB: f64 = 1024.0;
         const MIB: f64 = 1024.0 * 1024.0;
         const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
 
-        let size = self.size as f64;
+        let size = bytes as f64;
         if size >= GIB {
             format!("{:.2} GiB", size / GIB)
         } else if size >= MIB {
@@ -34,11 +252,36 @@ impl TorrentResult {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[cfg(test)]
+impl TorrentResult {
+    /// A healthy 1 GiB result from YTS without a magnet, for tests to adjust
+    pub fn sample(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            indexer: "yts".to_string(),
+            size: 1 << 30,
+            seeders: 10,
+            leechers: 1,
+            magnet: String::new(),
+            selected: false,
+            sources: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PutioFile {
     pub id: u64,
     pub name: String,
     pub parent_id: u64,
+    #[serde(default)]
+    pub file_type: String,
+}
+
+impl PutioFile {
+    pub fn is_folder(&self) -> bool {
+        self.file_type == "FOLDER"
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,4 +293,10 @@ pub struct PutioTransferResponse {
 pub struct PutioTransfer {
     pub id: u64,
     pub name: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub percent_done: Option<u32>,
+    #[serde(default)]
+    pub size: Option<u64>,
 }
\ No newline at end of file
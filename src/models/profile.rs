@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::filters::parse_size;
+use super::{ReleaseInfo, TorrentResult};
+
+// How much of the 0-100 score each part can contribute
+const RESOLUTION_WEIGHT: f64 = 40.0;
+const SOURCE_WEIGHT: f64 = 25.0;
+const CODEC_WEIGHT: f64 = 20.0;
+const SEEDERS_WEIGHT: f64 = 15.0;
+
+/// Named rules for what makes a good release, e.g. "x265 1080p under 4 GB"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QualityProfile {
+    pub name: String,
+    /// Labels such as "1080p", "WEB-DL" or "x265", best first. An empty
+    /// list has no preference; a result not in a non-empty list gets no
+    /// points for that part.
+    pub preferred_resolutions: Vec<String>,
+    pub preferred_sources: Vec<String>,
+    pub preferred_codecs: Vec<String>,
+    /// Results with any of these are rejected outright
+    pub forbidden_resolutions: Vec<String>,
+    pub forbidden_sources: Vec<String>,
+    pub forbidden_codecs: Vec<String>,
+    /// Allowed size per resolution, e.g. `{"1080p": {"max": "4G"}}`
+    pub sizes: BTreeMap<String, SizeRange>,
+    pub min_seeds: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SizeRange {
+    /// Sizes like "700M" or "4.5G"
+    pub min: Option<String>,
+    pub max: Option<String>,
+}
+
+impl QualityProfile {
+    /// Score `result` from 0 to 100, or `None` if the profile rejects it.
+    /// Resolution, source and codec earn points by their rank in the
+    /// preferred lists; seeders add up to 15 points on a log scale.
+    pub fn score(&self, result: &TorrentResult) -> Option<u32> {
+        if result.seeders < self.min_seeds {
+            return None;
+        }
+
+        let release = result.release();
+        let resolution = |info: &ReleaseInfo| info.resolution;
+        let source = |info: &ReleaseInfo| info.source;
+        let codec = |info: &ReleaseInfo| info.codec;
+
+        if position(&self.forbidden_resolutions, release.resolution, resolution).is_some()
+            || position(&self.forbidden_sources, release.source, source).is_some()
+            || position(&self.forbidden_codecs, release.codec, codec).is_some()
+            || !self.size_allowed(result.size, &release)
+        {
+            return None;
+        }
+
+        let seeders = (f64::from(result.seeders) + 1.0).log10() / 3.0;
+        let score = RESOLUTION_WEIGHT * rank(&self.preferred_resolutions, release.resolution, resolution)
+            + SOURCE_WEIGHT * rank(&self.preferred_sources, release.source, source)
+            + CODEC_WEIGHT * rank(&self.preferred_codecs, release.codec, codec)
+            + SEEDERS_WEIGHT * seeders.min(1.0);
+
+        Some(score.round() as u32)
+    }
+
+    /// Whether `size` fits the range given for the release's resolution
+    fn size_allowed(&self, size: u64, release: &ReleaseInfo) -> bool {
+        let Some(resolution) = release.resolution else {
+            return true;
+        };
+        self.sizes.iter()
+            .filter(|(label, _)| ReleaseInfo::parse(label).resolution == Some(resolution))
+            .all(|(_, range)| {
+                range.min.as_deref().and_then(parse_size).is_none_or(|min| size >= min)
+                    && range.max.as_deref().and_then(parse_size).is_none_or(|max| size <= max)
+            })
+    }
+}
+
+/// Index of the first label that parses to `value`
+fn position<T: PartialEq>(labels: &[String], value: Option<T>, field: impl Fn(&ReleaseInfo) -> Option<T>) -> Option<usize> {
+    let value = value?;
+    labels.iter().position(|label| field(&ReleaseInfo::parse(label)).as_ref() == Some(&value))
+}
+
+/// 1.0 for the first preferred label down to 1/n for the last, 0.0 for
+/// anything else; an empty list treats every value as best
+fn rank<T: PartialEq>(labels: &[String], value: Option<T>, field: impl Fn(&ReleaseInfo) -> Option<T>) -> f64 {
+    if labels.is_empty() {
+        return 1.0;
+    }
+    position(labels, value, field).map_or(0.0, |i| (labels.len() - i) as f64 / labels.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|l| l.to_string()).collect()
+    }
+
+    fn result(title: &str, size: u64) -> TorrentResult {
+        TorrentResult { size, seeders: 999, ..TorrentResult::sample(title) }
+    }
+
+    #[test]
+    fn rejects_forbidden_releases_and_too_few_seeders() {
+        let profile = QualityProfile {
+            forbidden_resolutions: labels(&["480p"]),
+            forbidden_sources: labels(&["CAM"]),
+            min_seeds: 5,
+            ..QualityProfile::default()
+        };
+
+        assert_eq!(profile.score(&result("Dune 2021 480p WEB-DL", 1 << 30)), None);
+        assert_eq!(profile.score(&result("Dune 2021 1080p CAM", 1 << 30)), None);
+        assert_eq!(profile.score(&TorrentResult { seeders: 4, ..TorrentResult::sample("Dune 2021 1080p WEB-DL") }), None);
+        assert!(profile.score(&result("Dune 2021 1080p WEB-DL", 1 << 30)).is_some());
+    }
+
+    #[test]
+    fn applies_size_bounds_to_their_resolution_only() {
+        let mut sizes = BTreeMap::new();
+        sizes.insert("1080p".to_string(), SizeRange { min: Some("1G".to_string()), max: Some("4G".to_string()) });
+        let profile = QualityProfile { sizes, ..QualityProfile::default() };
+
+        assert_eq!(profile.score(&result("Dune 2021 1080p WEB-DL", 512 << 20)), None);
+        assert_eq!(profile.score(&result("Dune 2021 1080p WEB-DL", 5 << 30)), None);
+        assert!(profile.score(&result("Dune 2021 1080p WEB-DL", 1 << 30)).is_some());
+        assert!(profile.score(&result("Dune 2021 1080p WEB-DL", 4 << 30)).is_some());
+        assert!(profile.score(&result("Dune 2021 2160p WEB-DL", 40 << 30)).is_some());
+    }
+
+    #[test]
+    fn ranks_preferences_by_order_and_weight() {
+        let profile = QualityProfile {
+            preferred_resolutions: labels(&["1080p", "720p"]),
+            preferred_sources: labels(&["BluRay", "WEB-DL"]),
+            preferred_codecs: labels(&["x265", "x264"]),
+            ..QualityProfile::default()
+        };
+        let score = |title| profile.score(&result(title, 2 << 30)).expect("not rejected");
+
+        // Everything first choice, with seeders at their cap, is a full score
+        assert_eq!(score("Dune 2021 1080p BluRay x265"), 100);
+        assert!(score("Dune 2021 1080p BluRay x265") > score("Dune 2021 720p BluRay x265"));
+        assert!(score("Dune 2021 720p BluRay x265") > score("Dune 2021 480p BluRay x265"));
+        // Dropping one rank costs most in resolution, then source, then codec
+        assert!(score("Dune 2021 720p BluRay x265") < score("Dune 2021 1080p WEB-DL x265"));
+        assert!(score("Dune 2021 1080p WEB-DL x265") < score("Dune 2021 1080p BluRay x264"));
+    }
+}
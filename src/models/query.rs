@@ -0,0 +1,291 @@
+use super::filters::{parse_size, TitleTerm};
+use super::{Filters, SortOrder};
+
+/// Search box text split into the keyword sent to the API and the inline
+/// filters applied locally, e.g.
+/// `dune "part two" seeds:>50 size:1G..8G idx:yts,tpb -cam sort:size`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ParsedQuery {
+    pub keyword: String,
+    pub min_seeds: Option<u32>,
+    pub max_seeds: Option<u32>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    /// Indexer names as typed; resolved against the known indexers by the caller
+    pub indexers: Option<Vec<String>>,
+    /// `sort:KEY[:asc|desc]`; keeps the panel's tie-breaker
+    pub sort: Option<SortOrder>,
+    /// Quoted phrases and `/regex/` terms the title must contain
+    pub phrases: Vec<String>,
+    /// `-word`, `-"phrase"` and `-/regex/` terms the title must not contain
+    pub excluded: Vec<String>,
+}
+
+impl ParsedQuery {
+    pub fn parse(input: &str) -> Self {
+        let mut query = ParsedQuery::default();
+        let mut words: Vec<String> = Vec::new();
+
+        for token in tokenize(input) {
+            if token.quoted {
+                // Phrases still go to the API so the search can find them
+                if token.negated {
+                    query.excluded.push(token.text);
+                } else {
+                    words.push(token.text.clone());
+                    query.phrases.push(token.text);
+                }
+                continue;
+            }
+
+            if token.negated {
+                query.excluded.push(token.text);
+                continue;
+            }
+
+            // `/regex/` filters locally and isn't sent to the API
+            if is_regex(&token.text) {
+                query.phrases.push(token.text);
+                continue;
+            }
+
+            if !query.apply_filter(&token.text) {
+                words.push(token.text);
+            }
+        }
+
+        query.keyword = words.join(" ");
+        query
+    }
+
+    /// Handle a `key:value` token; returns false if it isn't a known filter
+    fn apply_filter(&mut self, token: &str) -> bool {
+        let Some((key, value)) = token.split_once(':') else {
+            return false;
+        };
+        if value.is_empty() {
+            return false;
+        }
+
+        match key.to_lowercase().as_str() {
+            "seeds" | "seeders" | "s" => match parse_range(value, |v| v.parse::<u32>().ok()) {
+                Some((min, max)) => {
+                    self.min_seeds = min;
+                    self.max_seeds = max;
+                    true
+                }
+                None => false,
+            },
+            "size" => match parse_range(value, parse_size) {
+                Some((min, max)) => {
+                    self.min_size = min;
+                    self.max_size = max;
+                    true
+                }
+                None => false,
+            },
+            "idx" | "indexer" | "indexers" => {
+                let names: Vec<String> = value.split(',')
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                self.indexers = Some(names);
+                true
+            }
+            "sort" => match value.parse() {
+                Ok(sort) => {
+                    self.sort = Some(sort);
+                    true
+                }
+                Err(_) => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Overlay the inline filters on the panel's filter state. `indexers`
+    /// are the already-resolved display names for `idx:`.
+    pub fn apply_to(&self, filters: &Filters, indexers: Option<Vec<String>>) -> Filters {
+        let mut filters = filters.clone();
+
+        if self.min_seeds.is_some() || self.max_seeds.is_some() {
+            filters.min_seeds = self.min_seeds.unwrap_or(0);
+            filters.max_seeds = self.max_seeds;
+        }
+        if self.min_size.is_some() || self.max_size.is_some() {
+            filters.min_size = self.min_size;
+            filters.max_size = self.max_size;
+        }
+        if let Some(indexers) = indexers.filter(|i| !i.is_empty()) {
+            filters.indexers = indexers;
+        }
+        if let Some(sort) = self.sort {
+            filters.sort.key = sort.key;
+            filters.sort.descending = sort.descending;
+        }
+        filters.required.extend(self.phrases.iter().map(|p| TitleTerm::new(p)));
+        filters.excluded.extend(self.excluded.iter().map(|e| TitleTerm::new(e)));
+
+        filters
+    }
+}
+
+fn is_regex(text: &str) -> bool {
+    text.len() > 2 && text.starts_with('/') && text.ends_with('/')
+}
+
+struct Token {
+    text: String,
+    quoted: bool,
+    negated: bool,
+}
+
+/// Split on whitespace, keeping `"quoted phrases"` (optionally `-"negated"`) together
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut negated = false;
+        if c == '-' {
+            chars.next();
+            match chars.peek() {
+                Some(next) if !next.is_whitespace() => negated = true,
+                _ => {
+                    tokens.push(Token { text: "-".to_string(), quoted: false, negated: false });
+                    continue;
+                }
+            }
+        }
+
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            let text: String = chars.by_ref().take_while(|&c| c != '"').collect();
+            if !text.trim().is_empty() {
+                tokens.push(Token { text: text.trim().to_string(), quoted: true, negated });
+            }
+            continue;
+        }
+
+        let mut text = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            text.push(c);
+            chars.next();
+        }
+        tokens.push(Token { text, quoted: false, negated });
+    }
+
+    tokens
+}
+
+/// Parse `>N`, `>=N`, `<N`, `<=N`, `A..B`, `A..`, `..B` or a bare `N`
+/// (meaning at least N) into inclusive (min, max) bounds
+fn parse_range<T, F>(value: &str, parse: F) -> Option<(Option<T>, Option<T>)>
+where
+    T: Copy + Bound,
+    F: Fn(&str) -> Option<T>,
+{
+    if let Some(rest) = value.strip_prefix(">=") {
+        return Some((Some(parse(rest)?), None));
+    }
+    if let Some(rest) = value.strip_prefix('>') {
+        return Some((Some(parse(rest)?.next_up()), None));
+    }
+    if let Some(rest) = value.strip_prefix("<=") {
+        return Some((None, Some(parse(rest)?)));
+    }
+    if let Some(rest) = value.strip_prefix('<') {
+        return Some((None, Some(parse(rest)?.next_down())));
+    }
+    if let Some((min, max)) = value.split_once("..") {
+        let min = if min.is_empty() { None } else { Some(parse(min)?) };
+        let max = if max.is_empty() { None } else { Some(parse(max)?) };
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+        return Some((min, max));
+    }
+    Some((Some(parse(value)?), None))
+}
+
+/// Integer step used to turn strict comparisons into inclusive bounds
+trait Bound {
+    fn next_up(self) -> Self;
+    fn next_down(self) -> Self;
+}
+
+impl Bound for u32 {
+    fn next_up(self) -> Self {
+        self.saturating_add(1)
+    }
+    fn next_down(self) -> Self {
+        self.saturating_sub(1)
+    }
+}
+
+impl Bound for u64 {
+    fn next_up(self) -> Self {
+        self.saturating_add(1)
+    }
+    fn next_down(self) -> Self {
+        self.saturating_sub(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SortMode;
+
+    #[test]
+    fn splits_keyword_from_inline_filters() {
+        let query = ParsedQuery::parse(r#"dune "part two" seeds:>50 size:1G..8G idx:yts,tpb -cam sort:size"#);
+        assert_eq!(query.keyword, "dune part two");
+        assert_eq!(query.min_seeds, Some(51));
+        assert_eq!(query.max_seeds, None);
+        assert_eq!(query.min_size, Some(1 << 30));
+        assert_eq!(query.max_size, Some(8 << 30));
+        assert_eq!(query.indexers, Some(vec!["yts".to_string(), "tpb".to_string()]));
+        assert_eq!(query.sort.map(|s| s.key), Some(SortMode::Size));
+        assert_eq!(query.phrases, vec!["part two".to_string()]);
+        assert_eq!(query.excluded, vec!["cam".to_string()]);
+    }
+
+    #[test]
+    fn parses_range_forms() {
+        let seeds = |input: &str| {
+            let query = ParsedQuery::parse(input);
+            (query.min_seeds, query.max_seeds)
+        };
+        assert_eq!(seeds("x seeds:10"), (Some(10), None));
+        assert_eq!(seeds("x seeds:>=10"), (Some(10), None));
+        assert_eq!(seeds("x seeds:<10"), (None, Some(9)));
+        assert_eq!(seeds("x seeds:<=10"), (None, Some(10)));
+        assert_eq!(seeds("x seeds:10..100"), (Some(10), Some(100)));
+        assert_eq!(seeds("x seeds:..100"), (None, Some(100)));
+    }
+
+    #[test]
+    fn unknown_or_invalid_filters_stay_in_the_keyword() {
+        assert_eq!(ParsedQuery::parse("star trek: tng").keyword, "star trek: tng");
+        assert_eq!(ParsedQuery::parse("x seeds:lots").keyword, "x seeds:lots");
+        assert_eq!(ParsedQuery::parse("x seeds:..").keyword, "x seeds:..");
+        assert_eq!(ParsedQuery::parse("spider - man").keyword, "spider - man");
+    }
+
+    #[test]
+    fn regex_terms_filter_locally() {
+        let query = ParsedQuery::parse("dune /1080p|2160p/ -/hd-?ts/");
+        assert_eq!(query.keyword, "dune");
+        assert_eq!(query.phrases, vec!["/1080p|2160p/".to_string()]);
+        assert_eq!(query.excluded, vec!["/hd-?ts/".to_string()]);
+    }
+}
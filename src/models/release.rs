@@ -0,0 +1,510 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Metadata parsed from a scene-style release title such as
+/// `Show.Name.S01E02.2160p.WEB-DL.DDP5.1.Atmos.DV.HDR.H.265-GROUP`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ReleaseInfo {
+    /// Vertical resolution, e.g. 1080 for 1080p (4K/UHD = 2160)
+    pub resolution: Option<u16>,
+    pub source: Option<Source>,
+    pub codec: Option<Codec>,
+    pub hdr: bool,
+    pub dolby_vision: bool,
+    pub audio: Option<String>,
+    pub group: Option<String>,
+    pub year: Option<u16>,
+    pub episode: Option<Episode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Source {
+    Cam,
+    Telesync,
+    Telecine,
+    Screener,
+    Dvd,
+    Hdtv,
+    WebRip,
+    WebDl,
+    BluRay,
+    Remux,
+}
+
+impl Source {
+    /// Camera and telesync/telecine rips
+    pub fn is_pre_release(&self) -> bool {
+        matches!(self, Source::Cam | Source::Telesync | Source::Telecine | Source::Screener)
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Source::Cam => "CAM",
+            Source::Telesync => "TS",
+            Source::Telecine => "TC",
+            Source::Screener => "SCR",
+            Source::Dvd => "DVD",
+            Source::Hdtv => "HDTV",
+            Source::WebRip => "WEBRip",
+            Source::WebDl => "WEB-DL",
+            Source::BluRay => "BluRay",
+            Source::Remux => "Remux",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Codec {
+    X264,
+    X265,
+    Av1,
+    Vp9,
+    Xvid,
+}
+
+impl fmt::Display for Codec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Codec::X264 => "x264",
+            Codec::X265 => "x265",
+            Codec::Av1 => "AV1",
+            Codec::Vp9 => "VP9",
+            Codec::Xvid => "XviD",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Episode {
+    /// A single episode, or a run of episodes (`S01E01E02`, `S01E01-E03`)
+    Single { season: u16, episode: u16, last: Option<u16> },
+    /// A full season pack
+    Season(u16),
+    /// A multi-season pack, inclusive
+    Seasons(u16, u16),
+    /// "Complete" series pack without season numbers
+    Complete,
+}
+
+impl Episode {
+    /// Whether the release includes episode `episode` of season `season`.
+    /// A "Complete" pack is assumed to include everything.
+    pub fn contains(&self, season: u16, episode: u16) -> bool {
+        match *self {
+            Episode::Single { season: s, episode: first, last } => s == season && (first..=last.unwrap_or(first)).contains(&episode),
+            Episode::Season(s) => s == season,
+            Episode::Seasons(from, to) => (from..=to).contains(&season),
+            Episode::Complete => true,
+        }
+    }
+
+    /// The episode expected after this release, or `None` after a
+    /// "Complete" pack or when the numbers can't go any higher
+    pub fn next(&self) -> Option<(u16, u16)> {
+        match *self {
+            Episode::Single { season, episode, last } => Some((season, last.unwrap_or(episode).checked_add(1)?)),
+            Episode::Season(season) | Episode::Seasons(_, season) => Some((season.checked_add(1)?, 1)),
+            Episode::Complete => None,
+        }
+    }
+}
+
+impl fmt::Display for Episode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Episode::Single { season, episode, last: Some(last) } => write!(f, "S{:02}E{:02}-{:02}", season, episode, last),
+            Episode::Single { season, episode, last: None } => write!(f, "S{:02}E{:02}", season, episode),
+            Episode::Season(season) => write!(f, "S{:02}", season),
+            Episode::Seasons(from, to) => write!(f, "S{:02}-{:02}", from, to),
+            Episode::Complete => f.write_str("Complete"),
+        }
+    }
+}
+
+impl ReleaseInfo {
+    pub fn parse(title: &str) -> Self {
+        let lower = title.to_lowercase();
+        // Words split on scene separators; '-' and '+' are kept so that
+        // "web-dl", "dts-hd" and "hdr10+" survive as a single word
+        let words: Vec<&str> = lower
+            .split(|c: char| c.is_whitespace() || ".,_/[](){}".contains(c))
+            .filter(|w| !w.is_empty())
+            .collect();
+        // The same words further split on '-', e.g. "x264-group" -> "x264", "group"
+        let parts: Vec<&str> = words.iter()
+            .flat_map(|w| w.split('-'))
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        let mut info = ReleaseInfo {
+            group: parse_group(title),
+            ..Default::default()
+        };
+        // Index of the first part that is clearly release metadata; the
+        // year is the last one found before it
+        let mut first_marker = parts.len();
+        let mut mark = |i: usize| first_marker = first_marker.min(i);
+
+        for (i, part) in parts.iter().enumerate() {
+            if let Some(height) = parse_resolution(part) {
+                info.resolution = Some(height);
+                mark(i);
+            }
+
+            // Later source keywords win ("Cam.2018.1080p.BluRay" is a BluRay),
+            // except that a remux stays a remux
+            if let Some(source) = parse_source(part) {
+                if info.source != Some(Source::Remux) {
+                    info.source = Some(source);
+                }
+                mark(i);
+            }
+
+            if let Some(codec) = parse_codec(part) {
+                info.codec = Some(codec);
+                mark(i);
+            }
+
+            match *part {
+                "hdr" | "hdr10" | "hdr10+" | "hdr10plus" | "hlg" => {
+                    info.hdr = true;
+                    mark(i);
+                }
+                "dv" | "dovi" | "dolbyvision" => {
+                    info.dolby_vision = true;
+                    mark(i);
+                }
+                "vision" if i > 0 && parts[i - 1] == "dolby" => info.dolby_vision = true,
+                _ => {}
+            }
+
+            if info.episode.is_none() {
+                if let Some(episode) = parse_episode(part, parts.get(i + 1).copied()) {
+                    info.episode = Some(episode);
+                    mark(i);
+                }
+            }
+        }
+
+        // Multi-word forms that the part scan can't see
+        for (i, word) in words.iter().enumerate() {
+            match *word {
+                "blu-ray" if info.source != Some(Source::Remux) => info.source = Some(Source::BluRay),
+                "season" => {
+                    if let Some(season) = words.get(i + 1).and_then(|w| w.parse().ok()) {
+                        info.episode.get_or_insert(Episode::Season(season));
+                    }
+                }
+                "complete" => {
+                    info.episode.get_or_insert(Episode::Complete);
+                }
+                _ => {}
+            }
+        }
+        if lower.contains("h.264") {
+            info.codec.get_or_insert(Codec::X264);
+        } else if lower.contains("h.265") {
+            info.codec.get_or_insert(Codec::X265);
+        }
+
+        info.year = parse_year(&parts, first_marker);
+        info.audio = parse_audio(&lower, &words);
+        info
+    }
+
+    /// Resolution as a display label, e.g. "1080p"
+    pub fn resolution_label(&self) -> Option<String> {
+        self.resolution.map(|height| format!("{}p", height))
+    }
+
+    /// "DV", "HDR" or "DV HDR"
+    pub fn hdr_label(&self) -> Option<&'static str> {
+        match (self.dolby_vision, self.hdr) {
+            (true, true) => Some("DV HDR"),
+            (true, false) => Some("DV"),
+            (false, true) => Some("HDR"),
+            (false, false) => None,
+        }
+    }
+}
+
+fn parse_resolution(part: &str) -> Option<u16> {
+    match part {
+        "4k" | "uhd" => return Some(2160),
+        "8k" => return Some(4320),
+        _ => {}
+    }
+
+    // 1920x1080 style
+    if let Some((_, height)) = part.split_once('x') {
+        if let Ok(height) = height.parse::<u16>() {
+            if (240..=4320).contains(&height) && part.len() > 5 {
+                return Some(height);
+            }
+        }
+    }
+
+    let digits = part.strip_suffix('p').or_else(|| part.strip_suffix('i'))?;
+    let height: u16 = digits.parse().ok()?;
+    matches!(height, 240 | 360 | 480 | 540 | 576 | 720 | 1080 | 1440 | 2160 | 4320).then_some(height)
+}
+
+fn parse_source(part: &str) -> Option<Source> {
+    Some(match part {
+        "remux" | "bdremux" => Source::Remux,
+        "bluray" | "bdrip" | "brrip" | "bd" | "bd25" | "bd50" | "uhdbd" => Source::BluRay,
+        "webdl" | "web" | "amzn" | "nf" | "dsnp" | "atvp" | "hmax" => Source::WebDl,
+        "webrip" => Source::WebRip,
+        "hdtv" | "pdtv" | "sdtv" | "hdtvrip" => Source::Hdtv,
+        "dvd" | "dvdrip" | "dvd5" | "dvd9" | "dvdr" => Source::Dvd,
+        "scr" | "screener" | "dvdscr" | "bdscr" => Source::Screener,
+        "tc" | "telecine" | "hdtc" => Source::Telecine,
+        "ts" | "telesync" | "hdts" | "pdvd" => Source::Telesync,
+        "cam" | "camrip" | "hdcam" => Source::Cam,
+        _ => return None,
+    })
+}
+
+fn parse_codec(part: &str) -> Option<Codec> {
+    Some(match part {
+        "x264" | "h264" | "avc" => Codec::X264,
+        "x265" | "h265" | "hevc" => Codec::X265,
+        "av1" => Codec::Av1,
+        "vp9" => Codec::Vp9,
+        "xvid" | "divx" => Codec::Xvid,
+        _ => return None,
+    })
+}
+
+/// `s01e02`, `s01e02e03`, `s01e02` followed by `e03`, `1x02`, `s01`, `s01` followed by `s03`/`03`
+fn parse_episode(part: &str, next: Option<&str>) -> Option<Episode> {
+    if let Some((season, episode)) = part.split_once('x') {
+        let season: u16 = season.parse().ok()?;
+        let episode: u16 = episode.parse().ok()?;
+        return (season < 100 && !part.starts_with('0')).then_some(Episode::Single { season, episode, last: None });
+    }
+
+    let rest = part.strip_prefix('s')?;
+    let season_len = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    if season_len == 0 || season_len > 3 {
+        return None;
+    }
+    let season: u16 = rest[..season_len].parse().ok()?;
+    let rest = &rest[season_len..];
+
+    if rest.is_empty() {
+        // Season pack, possibly a range split across the '-': "s01-s03" / "s01-03"
+        if let Some(to) = next.and_then(|n| n.strip_prefix('s').unwrap_or(n).parse::<u16>().ok()) {
+            if to > season && to < 100 {
+                return Some(Episode::Seasons(season, to));
+            }
+        }
+        return Some(Episode::Season(season));
+    }
+
+    // One or more "eNN" groups
+    if !rest.starts_with('e') {
+        return None;
+    }
+    let mut episodes = rest.split('e').skip(1).map(|e| e.parse::<u16>().ok());
+    let episode = episodes.next()??;
+    let mut last = None;
+    for e in episodes {
+        last = Some(e?);
+    }
+    if last.is_none() {
+        last = next.and_then(|n| n.strip_prefix('e')).and_then(|n| n.parse().ok());
+    }
+
+    Some(Episode::Single { season, episode, last: last.filter(|&l| l > episode) })
+}
+
+fn parse_year(parts: &[&str], first_marker: usize) -> Option<u16> {
+    let year = |part: &&str| part.parse::<u16>().ok().filter(|y| part.len() == 4 && (1900..=2099).contains(y));
+
+    // Prefer the last year before the metadata (so "2001 A Space Odyssey 1968"
+    // picks 1968), but never the very first word, which is usually the title
+    parts[..first_marker].iter().skip(1).rev().find_map(year)
+        .or_else(|| parts.iter().skip(1).find_map(year))
+}
+
+fn parse_audio(lower: &str, words: &[&str]) -> Option<String> {
+    let mut codec = None;
+    for word in words {
+        let found = if word.starts_with("ddp") || word.starts_with("dd+") || word.starts_with("eac3") {
+            "DDP"
+        } else if word.starts_with("truehd") {
+            "TrueHD"
+        } else if word.starts_with("dts-hd") || word.starts_with("dtshd") {
+            "DTS-HD"
+        } else if *word == "dts-x" || *word == "dtsx" {
+            "DTS:X"
+        } else if word.starts_with("dts") {
+            "DTS"
+        } else if word.starts_with("dd5") || word.starts_with("dd2") || word.starts_with("ac3") || *word == "dd" {
+            "DD"
+        } else if word.starts_with("aac") {
+            "AAC"
+        } else if word.starts_with("flac") {
+            "FLAC"
+        } else if word.starts_with("opus") {
+            "Opus"
+        } else if *word == "mp3" {
+            "MP3"
+        } else {
+            continue;
+        };
+        codec = Some(found);
+        break;
+    }
+    let codec = codec?;
+
+    let channels = ["7.1", "5.1", "2.0", "1.0"].into_iter().find(|ch| {
+        lower.match_indices(ch).any(|(i, _)| {
+            let after = lower[i + ch.len()..].chars().next();
+            !after.is_some_and(|c| c.is_ascii_digit())
+        })
+    });
+
+    let mut audio = codec.to_string();
+    if let Some(channels) = channels {
+        audio.push(' ');
+        audio.push_str(channels);
+    }
+    if words.contains(&"atmos") {
+        audio.push_str(" Atmos");
+    }
+    Some(audio)
+}
+
+/// Words that end up after a '-' without being a group ("WEB-DL", "DTS-HD MA")
+fn is_metadata(word: &str) -> bool {
+    matches!(word, "dl" | "rip" | "ray" | "hd" | "ma" | "x")
+        || parse_codec(word).is_some()
+        || parse_source(word).is_some()
+        || parse_resolution(word).is_some()
+}
+
+/// Release group: the `-GROUP` suffix of scene names, or a leading `[Group]`
+/// tag as used by anime fansubs
+fn parse_group(title: &str) -> Option<String> {
+    let trimmed = title.trim();
+
+    // Drop trailing tracker tags and file extensions: "-GRP[eztv]", "-GRP.mkv"
+    let mut end = trimmed;
+    loop {
+        let before = end;
+        if end.ends_with(']') {
+            if let Some(open) = end.rfind('[') {
+                end = end[..open].trim_end();
+            }
+        }
+        for ext in [".mkv", ".mp4", ".avi"] {
+            // Compare the original bytes: lowercasing can change their length
+            let cut = end.len().saturating_sub(ext.len());
+            if end.get(cut..).is_some_and(|tail| tail.eq_ignore_ascii_case(ext)) {
+                end = &end[..cut];
+            }
+        }
+        if end == before {
+            break;
+        }
+    }
+
+    if let Some(dash) = end.rfind('-') {
+        let group = &end[dash + 1..];
+        let valid = !group.is_empty()
+            && group.len() <= 20
+            && group.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !is_metadata(&group.to_lowercase())
+            && group.parse::<u32>().is_err()
+            && !end[..dash].ends_with(' ');
+        if valid {
+            return Some(group.to_string());
+        }
+    }
+
+    let rest = trimmed.strip_prefix('[')?;
+    let close = rest.find(']')?;
+    let group = rest[..close].trim();
+    (!group.is_empty()).then(|| group.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_scene_episode_title() {
+        let info = ReleaseInfo::parse("Show.Name.S01E02.2160p.WEB-DL.DDP5.1.Atmos.DV.HDR.H.265-GROUP");
+        assert_eq!(info.resolution, Some(2160));
+        assert_eq!(info.source, Some(Source::WebDl));
+        assert_eq!(info.codec, Some(Codec::X265));
+        assert!(info.hdr);
+        assert!(info.dolby_vision);
+        assert_eq!(info.audio.as_deref(), Some("DDP 5.1 Atmos"));
+        assert_eq!(info.group.as_deref(), Some("GROUP"));
+        assert_eq!(info.episode, Some(Episode::Single { season: 1, episode: 2, last: None }));
+    }
+
+    #[test]
+    fn parses_a_movie_title() {
+        let info = ReleaseInfo::parse("2001 A Space Odyssey 1968 1080p BluRay x264-GRP");
+        assert_eq!(info.year, Some(1968));
+        assert_eq!(info.resolution, Some(1080));
+        assert_eq!(info.source, Some(Source::BluRay));
+        assert_eq!(info.codec, Some(Codec::X264));
+        assert_eq!(info.group.as_deref(), Some("GRP"));
+        assert_eq!(info.episode, None);
+    }
+
+    #[test]
+    fn remux_is_not_downgraded_by_a_later_source() {
+        let info = ReleaseInfo::parse("Movie.2020.2160p.UHD.BluRay.REMUX.HDR.HEVC-GRP");
+        assert_eq!(info.source, Some(Source::Remux));
+    }
+
+    #[test]
+    fn parses_episode_ranges_and_packs() {
+        let episode = |title| ReleaseInfo::parse(title).episode;
+        assert_eq!(episode("Show S01E01E02 720p"), Some(Episode::Single { season: 1, episode: 1, last: Some(2) }));
+        assert_eq!(episode("Show S01E01-E03 720p"), Some(Episode::Single { season: 1, episode: 1, last: Some(3) }));
+        assert_eq!(episode("Show 2x05 HDTV"), Some(Episode::Single { season: 2, episode: 5, last: None }));
+        assert_eq!(episode("Show S03 1080p WEB"), Some(Episode::Season(3)));
+        assert_eq!(episode("Show S01-S03 1080p"), Some(Episode::Seasons(1, 3)));
+        assert_eq!(episode("Show Season 4 720p"), Some(Episode::Season(4)));
+        assert_eq!(episode("Show Complete Series 480p"), Some(Episode::Complete));
+    }
+
+    #[test]
+    fn finds_groups_behind_tags_and_extensions() {
+        let group = |title| ReleaseInfo::parse(title).group;
+        assert_eq!(group("Show.S01E01.720p.HDTV.x264-GRP[eztv]").as_deref(), Some("GRP"));
+        assert_eq!(group("Show.S01E01.720p.HDTV.x264-GRP.MKV").as_deref(), Some("GRP"));
+        assert_eq!(group("[SubGroup] Anime - 01 [1080p]").as_deref(), Some("SubGroup"));
+        assert_eq!(group("Movie 2020 1080p WEB-DL"), None);
+    }
+
+    #[test]
+    fn extensions_are_matched_on_the_original_bytes() {
+        // The Kelvin sign lowercases to a one-byte 'k', so ".m\u{212A}v" is
+        // not ".mkv" and must not be cut as if it were
+        let info = ReleaseInfo::parse("Movie 1080p \u{212A}elvin-GRP.m\u{212A}v");
+        assert_eq!(info.resolution, Some(1080));
+        assert_eq!(info.group, None);
+        let info = ReleaseInfo::parse("Movie 1080p \u{212A}elvin-GRP.MkV");
+        assert_eq!(info.group.as_deref(), Some("GRP"));
+    }
+
+    #[test]
+    fn next_episode_stops_at_the_largest_number() {
+        assert_eq!(Episode::Single { season: 1, episode: 2, last: Some(4) }.next(), Some((1, 5)));
+        assert_eq!(Episode::Season(2).next(), Some((3, 1)));
+        assert_eq!(Episode::Complete.next(), None);
+        assert_eq!(Episode::Single { season: 1, episode: u16::MAX, last: None }.next(), None);
+        assert_eq!(Episode::Season(u16::MAX).next(), None);
+        assert_eq!(ReleaseInfo::parse("Show S01E65535 720p").episode.and_then(|e| e.next()), None);
+    }
+}
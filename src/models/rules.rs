@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use super::filters::{parse_size, TitleTerm};
+use super::{Filters, ReleaseInfo, TorrentResult};
+
+/// What a watched search must turn up before the daemon sends it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcceptRules {
+    /// Allowed resolutions, e.g. "1080p" or "4k"; empty allows any
+    pub resolutions: Vec<String>,
+    pub min_seeds: Option<u32>,
+    /// Sizes like "700M" or "4.5G"
+    pub min_size: Option<String>,
+    pub max_size: Option<String>,
+    /// Words, phrases or `/regex/` terms the title must contain
+    pub require: Vec<String>,
+    /// Words, phrases or `/regex/` terms the title must not contain
+    pub exclude: Vec<String>,
+}
+
+impl AcceptRules {
+    /// Tighten `filters` with the seed, size and title rules
+    pub fn apply_to(&self, filters: &mut Filters) -> Result<(), String> {
+        if let Some(min_seeds) = self.min_seeds {
+            filters.min_seeds = filters.min_seeds.max(min_seeds);
+        }
+        if let Some(min_size) = &self.min_size {
+            let min_size = parse_size(min_size).ok_or_else(|| format!("invalid min_size '{}'", min_size))?;
+            filters.min_size = Some(filters.min_size.map_or(min_size, |current| current.max(min_size)));
+        }
+        if let Some(max_size) = &self.max_size {
+            let max_size = parse_size(max_size).ok_or_else(|| format!("invalid max_size '{}'", max_size))?;
+            filters.max_size = Some(filters.max_size.map_or(max_size, |current| current.min(max_size)));
+        }
+        filters.required.extend(self.require.iter().map(|t| TitleTerm::new(t)));
+        filters.excluded.extend(self.exclude.iter().map(|t| TitleTerm::new(t)));
+        Ok(())
+    }
+
+    /// Whether the release quality of `result` is allowed
+    pub fn accepts_quality(&self, result: &TorrentResult) -> bool {
+        if self.resolutions.is_empty() {
+            return true;
+        }
+        let Some(resolution) = result.release().resolution else {
+            return false;
+        };
+        self.resolutions.iter().any(|label| ReleaseInfo::parse(label).resolution == Some(resolution))
+    }
+}
@@ -0,0 +1,78 @@
+use super::filters::normalize;
+use super::release::Episode;
+use super::{looks_unsafe, ReleaseInfo, TorrentResult};
+
+/// Whether `title` is a release of `show`: the show name, optionally a
+/// year, then the episode, so "Severance" doesn't match "Severance Island"
+pub fn is_release_of(title: &str, show: &str) -> bool {
+    let title = normalize(title);
+    let show = normalize(show);
+    let Some(rest) = title.strip_prefix(&show).and_then(|rest| rest.strip_prefix(' ')) else {
+        return false;
+    };
+
+    let mut words = rest.split(' ');
+    let mut word = words.next();
+    if word.is_some_and(|w| w.len() == 4 && (w.starts_with("19") || w.starts_with("20")) && w.parse::<u16>().is_ok()) {
+        word = words.next();
+    }
+    word.is_some_and(|w| w == "season" || w == "complete" || ReleaseInfo::parse(w).episode.is_some())
+}
+
+/// The first of `results` that is `show` and includes the episode. Single
+/// episodes win over season packs; whole-series packs and results that look
+/// fake are never picked.
+pub fn find_episode<'a>(results: &'a [TorrentResult], show: &str, season: u16, episode: u16) -> Option<&'a TorrentResult> {
+    let matching = |single: bool| {
+        results.iter().find(move |result| {
+            let Some(found) = result.release().episode else { return false };
+            matches!(found, Episode::Single { .. }) == single
+                && found != Episode::Complete
+                && found.contains(season, episode)
+                && is_release_of(&result.title, show)
+                && !looks_unsafe(result)
+        })
+    };
+    matching(true).or_else(|| matching(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_show_then_an_optional_year_and_the_episode() {
+        assert!(is_release_of("Severance.S02E04.1080p.WEB", "Severance"));
+        assert!(is_release_of("Severance 2022 S01E01 720p", "severance"));
+        assert!(is_release_of("Severance S02 Complete 1080p", "Severance"));
+        assert!(is_release_of("Severance Season 2 1080p", "Severance"));
+        assert!(!is_release_of("Severance Island S01E01 720p", "Severance"));
+        assert!(!is_release_of("The Severance S01E01 720p", "Severance"));
+        assert!(!is_release_of("Severance 1080p", "Severance"));
+    }
+
+    #[test]
+    fn prefers_the_episode_over_a_season_pack() {
+        let results = [
+            TorrentResult::sample("Severance Island S02E04 1080p"),
+            TorrentResult::sample("Severance S02 1080p WEB"),
+            TorrentResult::sample("Severance S02E04 1080p WEB"),
+            TorrentResult::sample("Severance S02E05 1080p WEB"),
+        ];
+
+        let found = find_episode(&results, "Severance", 2, 4).map(|r| r.title.as_str());
+        assert_eq!(found, Some("Severance S02E04 1080p WEB"));
+        let found = find_episode(&results, "Severance", 2, 6).map(|r| r.title.as_str());
+        assert_eq!(found, Some("Severance S02 1080p WEB"));
+        assert!(find_episode(&results, "Severance", 3, 1).is_none());
+    }
+
+    #[test]
+    fn skips_other_shows_and_unsafe_releases() {
+        let results = [
+            TorrentResult::sample("Severance Island S01E01 1080p"),
+            TorrentResult { size: 1 << 20, ..TorrentResult::sample("Severance S01E01 1080p") },
+        ];
+        assert!(find_episode(&results, "Severance", 1, 1).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+use super::filters::{contains_words, normalize};
+use super::release::Episode;
+use super::TorrentResult;
+
+/// Extensions that never belong in a video release
+const EXECUTABLES: [&str; 8] = ["exe", "scr", "bat", "cmd", "msi", "lnk", "vbs", "pif"];
+/// Extensions that hide what's inside a video release
+const ARCHIVES: [&str; 3] = ["zip", "rar", "7z"];
+/// Words fake video releases use to get something run or installed
+const MALWARE_WORDS: [&str; 9] = [
+    "password", "codec", "keygen", "setup", "installer", "free download",
+    "download link", "direct download", "watch online",
+];
+
+// Smallest believable size of a movie or pack at each resolution; single
+// episodes may be a fifth of it
+const MIN_SIZES_MB: [(u16, u64); 4] = [(2160, 1500), (1080, 500), (720, 250), (480, 100)];
+
+// A swarm this big with next to nobody downloading is often inflated, but
+// healthy old releases look the same, so it only ever warns
+const FAKE_SWARM_SEEDERS: u32 = 500;
+const FAKE_SWARM_RATIO: u32 = 100;
+
+/// Why `result` looks like a fake or malware, empty if nothing stands out
+pub fn suspicions(result: &TorrentResult) -> Vec<String> {
+    let mut reasons = content_suspicions(result);
+    if result.seeders >= FAKE_SWARM_SEEDERS && result.seeders >= FAKE_SWARM_RATIO * result.leechers.max(1) {
+        reasons.push(format!("{} seeders but {} leechers, typical of a fake swarm", result.seeders, result.leechers));
+    }
+    reasons
+}
+
+/// Whether `result` should never be sent without asking: anything
+/// suspicious about its title or size. A lopsided swarm alone isn't enough.
+pub fn looks_unsafe(result: &TorrentResult) -> bool {
+    !content_suspicions(result).is_empty()
+}
+
+/// Suspicions raised by the title and size
+fn content_suspicions(result: &TorrentResult) -> Vec<String> {
+    let mut reasons = Vec::new();
+    let title = result.title.to_lowercase();
+    let release = result.release();
+    let video = release.resolution.is_some() || release.source.is_some() || release.episode.is_some();
+
+    if let Some(ext) = EXECUTABLES.iter().find(|ext| has_extension(&title, ext)) {
+        reasons.push(format!("Executable file (.{}) in the title", ext));
+    } else if let Some(ext) = ARCHIVES.iter().find(|ext| video && has_extension(&title, ext)) {
+        reasons.push(format!("Video release packed in a .{} archive", ext));
+    }
+
+    if let Some(resolution) = release.resolution {
+        let min_mb = MIN_SIZES_MB.iter()
+            .find(|(at_least, _)| resolution >= *at_least)
+            .map(|(_, mb)| if matches!(release.episode, Some(Episode::Single { .. })) { mb / 5 } else { *mb });
+        if min_mb.is_some_and(|mb| result.size < mb * 1024 * 1024) {
+            reasons.push(format!("Too small for {}p ({})", resolution, result.size_str()));
+        }
+    }
+
+    if video {
+        let normalized = normalize(&result.title);
+        if let Some(word) = MALWARE_WORDS.iter().find(|word| contains_words(&normalized, word)) {
+            reasons.push(format!("\"{}\" in a video release, a common malware lure", word));
+        } else if contains_words(&normalized, "wmv") && release.resolution.is_some_and(|r| r >= 720) {
+            reasons.push("HD release as WMV, a common malware lure".to_string());
+        }
+    }
+
+    reasons
+}
+
+/// Whether `title` (lowercase) has `.ext` at the end of a word, as in
+/// "movie.mkv.exe" or "movie.zip (1080p)" but not "bat.out.of.hell"
+fn has_extension(title: &str, ext: &str) -> bool {
+    let suffix = format!(".{}", ext);
+    title.match_indices(&suffix).any(|(start, _)| {
+        let end = start + suffix.len();
+        title[end..].chars().next().is_none_or(|c| c.is_whitespace() || matches!(c, ')' | ']' | '}'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(title: &str) -> TorrentResult {
+        TorrentResult { size: 4 << 30, seeders: 120, leechers: 30, ..TorrentResult::sample(title) }
+    }
+
+    #[test]
+    fn flags_executables_at_the_end_of_a_word() {
+        assert!(looks_unsafe(&sample("Dune.2021.1080p.WEB-DL.mkv.exe")));
+        assert!(looks_unsafe(&sample("Dune 2021 1080p Setup.exe")));
+        assert!(looks_unsafe(&sample("Dune 2021 (Player.scr) 1080p")));
+        assert!(looks_unsafe(&sample("Dune 2021 1080p BluRay.rar")));
+        assert!(!looks_unsafe(&sample("Dune.Executive.Cut.2021.1080p.BluRay")));
+        assert!(!looks_unsafe(&sample("Bat.Out.Of.Hell.1977.1080p.BluRay")));
+        // Archives are only suspicious around video
+        assert!(!looks_unsafe(&sample("Wallpapers Collection.zip")));
+    }
+
+    #[test]
+    fn flags_lures_as_whole_words_only() {
+        assert!(looks_unsafe(&sample("Dune 2021 1080p WEB-DL Setup")));
+        assert!(looks_unsafe(&sample("Dune 2021 1080p Codec Pack")));
+        assert!(!looks_unsafe(&sample("A Simple Example 2021 1080p WEB-DL")));
+        assert!(!looks_unsafe(&sample("Passwordless 2021 1080p WEB-DL")));
+    }
+
+    #[test]
+    fn flags_sizes_too_small_for_the_resolution() {
+        let tiny = TorrentResult { size: 200 << 20, ..sample("Dune 2021 1080p BluRay") };
+        assert_eq!(suspicions(&tiny), ["Too small for 1080p (200.00 MiB)"]);
+        // Single episodes may be a fifth of a movie
+        assert!(suspicions(&TorrentResult { size: 200 << 20, ..sample("Severance S02E04 1080p") }).is_empty());
+    }
+
+    #[test]
+    fn only_warns_about_a_lopsided_swarm() {
+        let swarm = TorrentResult { seeders: 5000, leechers: 2, ..sample("Dune 2021 1080p BluRay") };
+        assert_eq!(suspicions(&swarm).len(), 1);
+        assert!(!looks_unsafe(&swarm));
+    }
+
+    #[test]
+    fn leaves_clean_releases_alone() {
+        assert!(suspicions(&sample("Dune.Part.Two.2024.1080p.WEB-DL.DDP5.1.x264-GROUP")).is_empty());
+        assert!(suspicions(&sample("Severance S02E04 1080p WEB H264")).is_empty());
+    }
+}
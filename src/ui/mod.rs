@@ -1,17 +1,30 @@
 pub mod setup;
 
 use crossterm::{
-    cursor, event::{self, Event, KeyCode, KeyEvent},
+    cursor, event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
     execute, queue, style::{Color, Print, SetBackgroundColor, SetForegroundColor},
     terminal::{self, ClearType},
 };
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::api::{ChillClient, PutioClient};
-use crate::config::Config;
-use crate::models::TorrentResult;
+use crate::config::{unix_now, Config, History, SavedSearches, SearchCache, SentLog, SeriesTracker};
+use crate::models::filters::parse_size;
+use crate::models::series::find_episode;
+use crate::models::{fuzzy_match, merge_duplicates, suspicions, Filters, IndexerRegistry, ParsedQuery, QualityProfile, ReleaseInfo, SortMode, SortOrder, TorrentResult};
+
+/// Indexer replies to the current search that the UI hasn't taken yet
+type SearchSlot = Arc<Mutex<Vec<IndexerReply>>>;
+/// The release a grab found for a tracked show, if any
+type GrabSlot = Arc<Mutex<Option<Result<Option<TorrentResult>, String>>>>;
+/// How each transfer of a send went, in order, or why none could be added
+type SendSlot = Arc<Mutex<Option<Result<Vec<Result<(), String>>, String>>>>;
+/// Label, right-aligned tag and color of a line in a sidebar list
+type SidebarLine = (String, String, Color);
 
 // Layout constants
 struct Layout;
@@ -20,16 +33,20 @@ impl Layout {
     const MARGIN_X: u16 = 1;  // Horizontal margin (left/right)
     const MARGIN_Y: u16 = 1;  // Vertical margin (top/bottom)
     const LEFT_PANEL_WIDTH: u16 = 22;
-    const LEFT_PANEL_CONTENT_WIDTH: usize = 21;
     const RESULTS_X_OFFSET: u16 = 23;
     const FILTER_BOX_CONTENT_WIDTH: usize = 17;
     const STATUS_BAR_LINES: u16 = 3;
     const HEADER_HEIGHT: u16 = 3;
+    const RELEASE_COLUMN_WIDTH: usize = 22;
+    const MIN_TITLE_WIDTH: usize = 30;  // Release column is hidden below this
+    const DETAIL_PANE_HEIGHT: u16 = 11;
+    const MIN_RESULTS_HEIGHT: u16 = 8;  // Detail pane is hidden if the table would get shorter
 }
 
 // Dracula theme colors
 struct DraculaTheme;
 
+#[allow(dead_code)] // Full palette kept for reference
 impl DraculaTheme {
     const BG: Color = Color::Rgb { r: 40, g: 42, b: 54 };
     const BG_LIGHTER: Color = Color::Rgb { r: 68, g: 71, b: 90 };
@@ -45,18 +62,148 @@ impl DraculaTheme {
     const YELLOW: Color = Color::Rgb { r: 241, g: 250, b: 140 };
 }
 
+/// Terminal stream the interface is drawn on. The picker draws on stderr
+/// so stdout stays free for the chosen results.
+#[derive(Clone, Copy, PartialEq)]
+enum Screen {
+    Stdout,
+    Stderr,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 enum Panel {
     Search,
     Filters,
+    Saved,
+    Shows,
     Results,
 }
 
-#[derive(Clone, Copy, PartialEq)]
-enum SortMode {
-    Seeders,
-    Size,
-    Name,
+/// A line in the results table: a result, or one of the per-indexer copies
+/// of a merged result when its group is expanded
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Row {
+    Result(usize),
+    Source(usize, usize),
+}
+
+/// History recall in the search bar. `draft` is what was typed before
+/// recall started and comes back if it is cancelled.
+#[derive(Clone, PartialEq, Debug)]
+enum Recall {
+    /// Up/Down through past queries containing the draft, best first
+    Browse { draft: String, index: usize },
+    /// Ctrl-R reverse search; Ctrl-R again skips to the next match
+    Search { draft: String, needle: String, index: usize },
+}
+
+/// A line of input typed in the search bar on behalf of another panel
+#[derive(Clone, PartialEq, Debug)]
+enum Prompt {
+    /// Name for saving `query` as a saved search
+    SaveSearch { query: String, name: String },
+    /// Show to track, optionally with the episode to start from
+    TrackShow(String),
+}
+
+impl Prompt {
+    fn label(&self) -> &'static str {
+        match self {
+            Prompt::SaveSearch { .. } => "Save as: ",
+            Prompt::TrackShow(_) => "Track show: ",
+        }
+    }
+
+    fn text(&self) -> &String {
+        match self {
+            Prompt::SaveSearch { name, .. } => name,
+            Prompt::TrackShow(input) => input,
+        }
+    }
+
+    fn text_mut(&mut self) -> &mut String {
+        match self {
+            Prompt::SaveSearch { name, .. } => name,
+            Prompt::TrackShow(input) => input,
+        }
+    }
+
+    fn hint(&self) -> String {
+        match self {
+            Prompt::SaveSearch { query, .. } => format!("query: {} ", query),
+            Prompt::TrackShow(_) => "Name, Name S02 or Name S02E04 ".to_string(),
+        }
+    }
+}
+
+/// One indexer's answer to a search, sent back from its thread
+struct IndexerReply {
+    /// Which fetch asked; answers to superseded ones are dropped
+    generation: u64,
+    /// API name
+    indexer: String,
+    result: Result<Vec<TorrentResult>, String>,
+    elapsed: Duration,
+}
+
+/// How an indexer is doing in the current search, shown in the Filters panel
+#[derive(Clone, Debug)]
+enum IndexerStatus {
+    Pending,
+    Done { count: usize, elapsed: Duration },
+    Failed(String),
+}
+
+impl IndexerStatus {
+    /// Short label and color: a spinner, "42 .3s", "42 2s" or "✗"
+    fn label(&self, spinner: char) -> (String, Color) {
+        match self {
+            IndexerStatus::Pending => (spinner.to_string(), DraculaTheme::COMMENT),
+            IndexerStatus::Done { count, elapsed } => {
+                let secs = elapsed.as_secs_f64();
+                let latency = if secs < 0.95 {
+                    format!(".{}s", ((secs * 10.0).round() as u32).max(1))
+                } else {
+                    format!("{:.0}s", secs)
+                };
+                (format!("{} {}", count, latency), DraculaTheme::FG_DIM)
+            }
+            IndexerStatus::Failed(_) => ("✗".to_string(), DraculaTheme::RED),
+        }
+    }
+}
+
+/// What the table showed before a search started, put back if it's cancelled
+struct ShownResults {
+    active_query: String,
+    raw_results: Vec<TorrentResult>,
+    results: Vec<TorrentResult>,
+    fetched: Option<FetchKey>,
+    cached_at: Option<u64>,
+    new_hashes: HashSet<String>,
+    expanded: HashSet<usize>,
+    fuzzy_query: String,
+    selected_index: usize,
+    scroll_offset: usize,
+}
+
+/// The API request behind a set of raw results
+#[derive(Clone, PartialEq, Debug)]
+struct FetchKey {
+    keyword: String,
+    /// Sorted API names
+    indexers: Vec<String>,
+    filter_nsfw: bool,
+}
+
+impl FetchKey {
+    /// Whether results fetched for `self` contain everything `other` would
+    /// return, so `other` can be served by filtering locally
+    fn covers(&self, other: &FetchKey) -> bool {
+        self.keyword == other.keyword
+            && self.filter_nsfw == other.filter_nsfw
+            && other.indexers.iter().all(|i| self.indexers.contains(i))
+    }
 }
 
 // Animation and rendering constants
@@ -65,7 +212,6 @@ struct AnimationConfig;
 impl AnimationConfig {
     const FRAME_INTERVAL_MS: u64 = 50;
     const TITLE_SCROLL_SPEED: u8 = 3;  // Frames between scroll updates
-    const TITLE_SCROLL_PAUSE: usize = 20;  // Frames to pause at ends
     const SEND_SUCCESS_DURATION_SECS: u64 = 2;
 }
 
@@ -77,13 +223,14 @@ struct LayoutCache {
     seeds_column: u16,
     source_column: u16,
     separator_column: u16,
-    right_border_column: usize,
+    release_column: Option<u16>,
+    score_column: Option<u16>,
     terminal_width: u16,
     terminal_height: u16,
 }
 
 impl LayoutCache {
-    fn new(term_width: u16, term_height: u16, results_x: u16) -> Self {
+    fn new(term_width: u16, term_height: u16, results_x: u16, score: bool) -> Self {
         let right_border_col = (term_width as usize).saturating_sub(Layout::MARGIN_X as usize + 1);
 
         // Position columns from right to left
@@ -93,9 +240,26 @@ impl LayoutCache {
         let seeds_start = seeds_end.saturating_sub(5);
         let size_end = seeds_start.saturating_sub(3);
         let size_start = size_end.saturating_sub(12);
-        let sep_pos = size_start.saturating_sub(3);
+        let mut sep_pos = size_start.saturating_sub(3);
+
+        // Profile score goes left of Size while a quality profile is active
+        let mut score_column = None;
+        if score {
+            let score_start = sep_pos.saturating_sub(5);
+            score_column = Some(score_start as u16);
+            sep_pos = score_start.saturating_sub(3);
+        }
+
+        let mut title_width = sep_pos.saturating_sub((results_x as usize) + 2 + 3 + 3 + 3);
 
-        let title_width = sep_pos.saturating_sub((results_x as usize) + 2 + 3 + 3 + 3);
+        // Release badges sit between title and size when there's room
+        let mut release_column = None;
+        if title_width >= Layout::MIN_TITLE_WIDTH + Layout::RELEASE_COLUMN_WIDTH + 3 {
+            let release_start = sep_pos.saturating_sub(Layout::RELEASE_COLUMN_WIDTH);
+            release_column = Some(release_start as u16);
+            sep_pos = release_start.saturating_sub(3);
+            title_width -= Layout::RELEASE_COLUMN_WIDTH + 3;
+        }
 
         Self {
             title_width,
@@ -103,14 +267,17 @@ impl LayoutCache {
             seeds_column: seeds_start as u16,
             source_column: source_start as u16,
             separator_column: sep_pos as u16,
-            right_border_column: right_border_col,
+            release_column,
+            score_column,
             terminal_width: term_width,
             terminal_height: term_height,
         }
     }
 
-    fn needs_update(&self, term_width: u16, term_height: u16) -> bool {
-        self.terminal_width != term_width || self.terminal_height != term_height
+    fn needs_update(&self, term_width: u16, term_height: u16, score: bool) -> bool {
+        self.terminal_width != term_width
+            || self.terminal_height != term_height
+            || self.score_column.is_some() != score
     }
 }
 
@@ -145,92 +312,282 @@ impl MarqueeCache {
     }
 }
 
+/// Compact, colored badges for the Release column
+fn release_badges(info: &ReleaseInfo) -> Vec<(String, Color)> {
+    let mut badges = Vec::new();
+
+    if let (Some(height), Some(label)) = (info.resolution, info.resolution_label()) {
+        let color = match height {
+            2160.. => DraculaTheme::PURPLE,
+            1080.. => DraculaTheme::GREEN,
+            720.. => DraculaTheme::CYAN,
+            _ => DraculaTheme::FG_DIM,
+        };
+        badges.push((label, color));
+    }
+    if let Some(source) = info.source {
+        let color = if source.is_pre_release() { DraculaTheme::RED } else { DraculaTheme::FG };
+        badges.push((source.to_string(), color));
+    }
+    if let Some(codec) = info.codec {
+        badges.push((codec.to_string(), DraculaTheme::FG_DIM));
+    }
+    if let Some(hdr) = info.hdr_label() {
+        badges.push((hdr.to_string(), DraculaTheme::YELLOW));
+    }
+
+    badges
+}
+
+/// Editable bounds in the Filters panel's LIMITS box
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Limit {
+    MinSeeds,
+    MaxSeeds,
+    MinSize,
+    MaxSize,
+}
+
+impl Limit {
+    const ALL: [Limit; 4] = [Limit::MinSeeds, Limit::MaxSeeds, Limit::MinSize, Limit::MaxSize];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Limit::MinSeeds => "Min seeds",
+            Limit::MaxSeeds => "Max seeds",
+            Limit::MinSize => "Min size",
+            Limit::MaxSize => "Max size",
+        }
+    }
+}
+
+/// Compact size that `parse_size` reads back, e.g. "700M" or "4.5G"
+fn short_size(bytes: u64) -> String {
+    const UNITS: [(&str, u64); 4] = [("T", 1 << 40), ("G", 1 << 30), ("M", 1 << 20), ("K", 1 << 10)];
+
+    for (unit, scale) in UNITS {
+        if bytes >= scale {
+            let value = bytes as f64 / scale as f64;
+            let text = format!("{:.1}", value);
+            return format!("{}{}", text.trim_end_matches(".0"), unit);
+        }
+    }
+    bytes.to_string()
+}
+
+/// Short relative age like "5m ago" for a unix timestamp
+fn format_age(timestamp: u64) -> String {
+    let secs = unix_now().saturating_sub(timestamp);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+/// Split `text` into at most `max_lines` lines of `width` chars, ending
+/// with "..." if it doesn't fit
+fn wrap_text(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut lines: Vec<String> = chars.chunks(width.max(1)).take(max_lines).map(|c| c.iter().collect()).collect();
+    if chars.len() > width * max_lines {
+        if let Some(last) = lines.last_mut() {
+            let keep = last.chars().count().saturating_sub(3);
+            *last = format!("{}...", last.chars().take(keep).collect::<String>());
+        }
+    }
+    lines
+}
+
 pub struct App {
     config: Config,
     chill_client: Option<ChillClient>,
     putio_client: Option<PutioClient>,
     query: String,
+    /// Merged but unfiltered results of the last fetch
+    raw_results: Vec<TorrentResult>,
+    /// What `raw_results` was fetched with, and what is being fetched now
+    fetched: Option<FetchKey>,
+    pending_fetch: Option<FetchKey>,
+    /// When `raw_results` came from the search cache, when they were fetched
+    cached_at: Option<u64>,
+    /// Cached results are showing while a fresh fetch runs
+    revalidating: bool,
+    /// Make the next fetch go to the API even if the cache is fresh
+    skip_cache: bool,
+    /// Query the current results belong to (the search box may have moved on)
+    active_query: String,
+    /// `raw_results` after filtering and sorting, as shown in the table
     results: Vec<TorrentResult>,
+    /// Merged results whose per-indexer copies are shown
+    expanded: HashSet<usize>,
     selected_index: usize,
     scroll_offset: usize,
     active_panel: Panel,
+    /// Indexers from the config, shared with `filters`
+    registry: Arc<IndexerRegistry>,
     available_indexers: Vec<String>,
-    selected_indexers: Vec<String>,
-    indexer_cursor: usize,
-    sort_by: SortMode,
+    filters: Filters,
     sort_cursor: usize,
-    min_seeds: u32,
-    filter_nsfw: bool,
     searching: bool,
     status_message: String,
     debug: bool,
     sending_to_putio: bool,
     sending_complete: bool,
+    /// Whether any transfer of the last send failed
+    send_failed: bool,
     sent_file_name: String,
+    /// Results being sent, recorded as sent once Put.io accepts them
+    sending: Vec<TorrentResult>,
     title_scroll_offset: usize,
     title_scroll_direction: i8,  // 1 = forward, -1 = backward
     frame_counter: u8,
-    marquee_scroll_offset: usize,
     should_animate: bool,
     cached_width: u16,
     cached_height: u16,
     spinner_frame: u8,
-    search_results: Arc<Mutex<Option<Result<Vec<TorrentResult>, String>>>>,
+    search_results: SearchSlot,
+    /// Bumped by every fetch and cancel, so late answers can be told apart
+    search_generation: u64,
+    /// The last settled results while a search is in flight
+    before_search: Option<ShownResults>,
+    /// Per-indexer progress of the last search, by API name
+    indexer_status: HashMap<String, IndexerStatus>,
+    /// Unmerged results of the indexers that have answered so far
+    streamed: Vec<TorrentResult>,
     send_complete: Arc<Mutex<bool>>,
+    send_result: SendSlot,
     layout_cache: Option<LayoutCache>,
     marquee_cache: MarqueeCache,
+    screen: Screen,
+    picker: bool,
+    picked: Option<Vec<TorrentResult>>,
+    show_details: bool,
+    sent_log: SentLog,
+    /// Text of the limit being edited in the Filters panel
+    limit_input: Option<String>,
+    limit_error: bool,
+    /// Filters from before the limit edit, which ESC puts back
+    limit_before: Option<Filters>,
+    /// `/` filter narrowing the current results, and whether it is being typed
+    fuzzy_query: String,
+    fuzzy_editing: bool,
+    history: History,
+    recall: Option<Recall>,
+    saved: SavedSearches,
+    saved_cursor: usize,
+    prompt: Option<Prompt>,
+    /// Saved search whose run is being fetched
+    running_saved: Option<String>,
+    /// Infohashes the last saved search run hadn't seen before, badged NEW
+    new_hashes: HashSet<String>,
+    series: SeriesTracker,
+    series_cursor: usize,
+    /// Show whose next episode is being looked for, and where the answer lands
+    grabbing: Option<String>,
+    grab_result: GrabSlot,
+    /// Outcome of the last grab, shown under the Shows list for that show
+    series_note: Option<(String, String)>,
 }
 
 impl App {
-    pub fn new(config: Config, debug: bool) -> Self {
+    pub fn new(config: Config, registry: Arc<IndexerRegistry>, debug: bool) -> Self {
         let chill_client = Self::create_chill_client(&config);
         let putio_client = Self::create_putio_client(&config);
+        let prefs = config.ui.clone();
 
-        Self {
+        let mut app = Self {
             config,
             chill_client,
             putio_client,
             query: String::new(),
+            raw_results: Vec::new(),
+            fetched: None,
+            pending_fetch: None,
+            cached_at: None,
+            revalidating: false,
+            skip_cache: false,
+            active_query: String::new(),
             results: Vec::new(),
+            expanded: HashSet::new(),
             selected_index: 0,
             scroll_offset: 0,
             active_panel: Panel::Search,
-            available_indexers: vec![
-                "all".to_string(),
-                "1337x".to_string(),
-                "EZTV".to_string(),
-                "nyaa.si".to_string(),
-                "RUtracker".to_string(),
-                "TPB".to_string(),
-                "RARBG".to_string(),
-                "Uindex".to_string(),
-                "YTS".to_string(),
-            ],
-            selected_indexers: vec!["all".to_string()],
-            indexer_cursor: 0,
-            sort_by: SortMode::Seeders,
+            available_indexers: std::iter::once("all".to_string())
+                .chain(registry.enabled().map(|indexer| indexer.name.clone()))
+                .collect(),
+            filters: Filters {
+                sort: prefs.sort,
+                indexers: Vec::new(),
+                min_seeds: prefs.min_seeds,
+                max_seeds: prefs.max_seeds,
+                min_size: prefs.min_size,
+                max_size: prefs.max_size,
+                filter_nsfw: prefs.filter_nsfw,
+                hide_suspicious: prefs.hide_suspicious,
+                registry: Arc::clone(&registry),
+                ..Filters::default()
+            },
+            registry,
             sort_cursor: 0,
-            min_seeds: 10,
-            filter_nsfw: true,
             searching: false,
             status_message: "Ready".to_string(),
             debug,
             sending_to_putio: false,
             sending_complete: false,
+            send_failed: false,
             sent_file_name: String::new(),
+            sending: Vec::new(),
             title_scroll_offset: 0,
             title_scroll_direction: 1,
             frame_counter: 0,
-            marquee_scroll_offset: 0,
             should_animate: true,
             cached_width: 0,
             cached_height: 0,
             spinner_frame: 0,
-            search_results: Arc::new(Mutex::new(None)),
+            search_results: Arc::new(Mutex::new(Vec::new())),
+            search_generation: 0,
+            before_search: None,
+            indexer_status: HashMap::new(),
+            streamed: Vec::new(),
             send_complete: Arc::new(Mutex::new(false)),
+            send_result: Arc::new(Mutex::new(None)),
             layout_cache: None,
             marquee_cache: MarqueeCache::new("+++ ChillTUI - chill.institute but from the terminal! Search for content and press enter to send results to Put.io +++    +++"),
+            screen: Screen::Stdout,
+            picker: false,
+            picked: None,
+            show_details: prefs.show_details,
+            sent_log: SentLog::load(),
+            limit_input: None,
+            limit_error: false,
+            limit_before: None,
+            fuzzy_query: String::new(),
+            fuzzy_editing: false,
+            history: History::load(),
+            recall: None,
+            saved: SavedSearches::load(),
+            saved_cursor: 0,
+            prompt: None,
+            running_saved: None,
+            new_hashes: HashSet::new(),
+            series: SeriesTracker::load(),
+            series_cursor: 0,
+            grabbing: None,
+            grab_result: Arc::new(Mutex::new(None)),
+            series_note: None,
+        };
+
+        // Drop indexers that no longer exist so a stale config can't hide everything
+        app.filters.indexers = prefs.selected_indexers.into_iter()
+            .filter(|i| app.available_indexers.contains(i))
+            .collect();
+        if app.filters.indexers.is_empty() {
+            app.filters.indexers.push("all".to_string());
         }
+
+        app
     }
 
     fn create_chill_client(config: &Config) -> Option<ChillClient> {
@@ -249,79 +606,146 @@ impl App {
 
     pub fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         terminal::enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        let mut out = io::stdout();
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
 
         let result = self.main_loop();
 
         // Cleanup
         execute!(
-            stdout,
+            out,
             terminal::LeaveAlternateScreen,
             cursor::Show
         )?;
         terminal::disable_raw_mode()?;
 
+        self.save_preferences();
+
         result
     }
 
+    /// Override the saved filters, e.g. with command line flags.
+    /// Indexers may be given by display name, API id or category.
+    pub fn set_filters(&mut self, indexers: &[String], min_seeds: u32, sort: SortOrder, filter_nsfw: bool, hide_suspicious: bool) {
+        let selected = self.resolve_indexers(indexers);
+        self.filters.indexers = if selected.is_empty() { vec!["all".to_string()] } else { selected };
+        self.filters.min_seeds = min_seeds;
+        self.filters.sort = sort;
+        self.filters.filter_nsfw = filter_nsfw;
+        self.filters.hide_suspicious = hide_suspicious;
+    }
+
+    /// Match indexer names (display name, API id or category, any case)
+    /// against the enabled indexers, dropping the ones we don't know
+    fn resolve_indexers(&self, names: &[String]) -> Vec<String> {
+        let mut selected: Vec<String> = Vec::new();
+        for indexer in names.iter().flat_map(|name| self.registry.resolve(name)) {
+            if self.available_indexers.contains(&indexer.name) && !selected.contains(&indexer.name) {
+                selected.push(indexer.name.clone());
+            }
+        }
+        selected
+    }
+
+    /// Run the results UI inline on stderr for `query` and return what the
+    /// user confirmed with Enter, or `None` if they quit. Nothing is sent
+    /// to Put.io and preferences are left untouched.
+    pub fn run_picker(mut self, query: &str) -> Result<Option<Vec<TorrentResult>>, Box<dyn std::error::Error>> {
+        self.screen = Screen::Stderr;
+        self.picker = true;
+        self.query = query.to_string();
+        self.marquee_cache = MarqueeCache::new("+++ ChillTUI picker - Space to mark results, Enter to print them, ESC to cancel +++    +++");
+
+        // Stay on the main screen: push what's there into scrollback so it
+        // survives, then draw over the now-empty viewport
+        let mut out = io::stderr();
+        let (_, height) = terminal::size()?;
+        execute!(out, Print("\n".repeat(height as usize)), cursor::MoveTo(0, 0), cursor::Hide)?;
+        terminal::enable_raw_mode()?;
+
+        self.perform_search()?;
+        let result = self.main_loop();
+
+        execute!(
+            out,
+            SetBackgroundColor(Color::Reset),
+            SetForegroundColor(Color::Reset),
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            cursor::Show
+        )?;
+        terminal::disable_raw_mode()?;
+
+        result?;
+        Ok(self.picked)
+    }
+
+    /// Write the current filter and sort choices back to the config file
+    fn save_preferences(&mut self) {
+        if self.picker {
+            return;
+        }
+
+        self.config.ui.sort = self.filters.sort;
+        self.config.ui.selected_indexers = self.filters.indexers.clone();
+        self.config.ui.min_seeds = self.filters.min_seeds;
+        self.config.ui.max_seeds = self.filters.max_seeds;
+        self.config.ui.min_size = self.filters.min_size;
+        self.config.ui.max_size = self.filters.max_size;
+        self.config.ui.filter_nsfw = self.filters.filter_nsfw;
+        self.config.ui.show_details = self.show_details;
+        self.config.ui.hide_suspicious = self.filters.hide_suspicious;
+
+        if let Err(e) = self.config.save() {
+            if self.debug {
+                eprintln!("[DEBUG] Failed to save preferences: {}", e);
+            }
+        }
+    }
+
     fn main_loop(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         loop {
-            // Check for completed search results
-            if self.searching {
-                if let Ok(mut guard) = self.search_results.try_lock() {
-                    if let Some(result) = guard.take() {
-                        match result {
-                            Ok(results) => {
-                                self.results = results;
-                                self.selected_index = 0;
-                                self.scroll_offset = 0;
-                                self.active_panel = Panel::Results;
-                                self.status_message = format!("✓ Found {} results", self.results.len());
-                                self.should_animate = true;
-
-                                if self.debug {
-                                    eprintln!("[DEBUG] Search completed: {} results", self.results.len());
-                                }
-                            }
-                            Err(e) => {
-                                self.status_message = format!("✗ Search error: {}", e);
-                                if self.debug {
-                                    eprintln!("[DEBUG] Search error: {}", e);
-                                }
-                            }
+            // Merge in whatever indexers have answered since the last frame
+            if self.searching || self.revalidating {
+                let replies = self.search_results.try_lock().map(|mut guard| std::mem::take(&mut *guard)).unwrap_or_default();
+                for reply in replies {
+                    self.receive_reply(reply);
+                }
+            }
+
+            // Check for a finished grab of a tracked show's next episode
+            if self.grabbing.is_some() {
+                let finished = self.grab_result.try_lock().ok().and_then(|mut guard| guard.take());
+                if let (Some(result), Some(name)) = (finished, self.grabbing.clone()) {
+                    self.grabbing = None;
+                    let next = self.series.list().iter().find(|show| show.name == name).map(|show| show.next_label());
+                    match result {
+                        Ok(Some(found)) => self.send_results(vec![found]),
+                        Ok(None) => {
+                            let note = format!("{} not out yet", next.unwrap_or_default());
+                            self.series_note = Some((name, note));
                         }
-                        self.searching = false;
+                        Err(e) => self.series_note = Some((name, format!("✗ {}", e))),
                     }
                 }
             }
 
             // Check for completed send to Put.io
             if self.sending_to_putio && !self.sending_complete {
-                if let Ok(guard) = self.send_complete.try_lock() {
-                    if *guard {
-                        self.sending_complete = true;
-
-                        // Update message to show completion
-                        let msg = &self.sent_file_name.clone();
-                        if msg.starts_with("Sending '") {
-                            // Single file: extract title
-                            if let Some(title) = msg.strip_prefix("Sending '").and_then(|s| s.strip_suffix("' to Put.io")) {
-                                self.sent_file_name = format!("Sent '{}' to Put.io!", title);
-                            }
-                        } else if let Some(num_str) = msg.split("Sending ").nth(1).and_then(|s| s.split(" files").next()) {
-                            self.sent_file_name = format!("Sent {} files to Put.io!", num_str);
+                let done = self.send_complete.try_lock().is_ok_and(|guard| *guard);
+                if done {
+                    self.sending_complete = true;
+                    let outcome = self.send_result.lock().ok().and_then(|mut slot| slot.take());
+                    self.finish_send(outcome.unwrap_or_else(|| Err("no answer".to_string())));
+
+                    // Schedule close after 2 seconds
+                    let send_complete_clone = Arc::clone(&self.send_complete);
+                    thread::spawn(move || {
+                        std::thread::sleep(std::time::Duration::from_secs(AnimationConfig::SEND_SUCCESS_DURATION_SECS));
+                        if let Ok(mut g) = send_complete_clone.lock() {
+                            *g = false;
                         }
-
-                        // Schedule close after 2 seconds
-                        let send_complete_clone = Arc::clone(&self.send_complete);
-                        thread::spawn(move || {
-                            std::thread::sleep(std::time::Duration::from_secs(2));
-                            if let Ok(mut g) = send_complete_clone.lock() {
-                                *g = false;
-                            }
-                        });
-                    }
+                    });
                 }
             }
 
@@ -341,12 +765,12 @@ impl App {
             // Only update animations when needed
             if self.should_animate {
                 self.frame_counter = self.frame_counter.wrapping_add(1);
-                if self.frame_counter % 3 == 0 {
+                if self.frame_counter.is_multiple_of(AnimationConfig::TITLE_SCROLL_SPEED) {
                     self.update_title_scroll();
                 }
             }
 
-            if event::poll(std::time::Duration::from_millis(50))? {
+            if event::poll(std::time::Duration::from_millis(AnimationConfig::FRAME_INTERVAL_MS))? {
                 if let Event::Key(key) = event::read()? {
                     if !self.handle_key(key)? {
                         break;
@@ -363,7 +787,7 @@ impl App {
         self.marquee_cache.advance();
 
         // Update spinner when searching or sending
-        if self.searching || self.sending_to_putio {
+        if self.searching || self.revalidating || self.sending_to_putio || self.grabbing.is_some() {
             self.spinner_frame = self.spinner_frame.wrapping_add(1);
         }
 
@@ -389,8 +813,14 @@ impl App {
     }
 
     fn draw(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut stdout = io::stdout();
+        match self.screen {
+            Screen::Stdout => self.draw_frame(&mut io::stdout()),
+            // stderr is unbuffered, so batch each frame into a single write
+            Screen::Stderr => self.draw_frame(&mut io::BufWriter::with_capacity(1 << 16, io::stderr())),
+        }
+    }
 
+    fn draw_frame<W: Write>(&mut self, out: &mut W) -> Result<(), Box<dyn std::error::Error>> {
         // Check if terminal size changed
         let (term_width, term_height) = terminal::size()?;
         let size_changed = term_width != self.cached_width || term_height != self.cached_height;
@@ -401,9 +831,10 @@ impl App {
         }
 
         // Update layout cache if needed
-        if self.layout_cache.is_none() || size_changed {
+        let score = self.profile().is_some();
+        if self.layout_cache.as_ref().is_none_or(|cache| cache.needs_update(term_width, term_height, score)) {
             let results_x = Layout::RESULTS_X_OFFSET + Layout::MARGIN_X;
-            self.layout_cache = Some(LayoutCache::new(term_width, term_height, results_x));
+            self.layout_cache = Some(LayoutCache::new(term_width, term_height, results_x, score));
         }
 
         // Calculate content area with margins
@@ -413,40 +844,45 @@ impl App {
         let content_height = term_height.saturating_sub(Layout::MARGIN_Y * 2);
 
         queue!(
-            stdout,
+            out,
             terminal::Clear(ClearType::All),
             SetBackgroundColor(DraculaTheme::BG),
             SetForegroundColor(DraculaTheme::FG)
         )?;
 
         // Draw search bar at top (with y offset)
-        self.draw_search_bar(&mut stdout, x_offset, content_width, y_offset)?;
+        self.draw_search_bar(out, x_offset, content_width, y_offset)?;
 
         // Draw horizontal separator
-        self.draw_horizontal_line(&mut stdout, x_offset, 2 + y_offset, content_width)?;
+        self.draw_horizontal_line(out, x_offset, 2 + y_offset, content_width)?;
 
         // Draw left panel (filters & sort)
-        self.draw_left_panel(&mut stdout, x_offset, Layout::LEFT_PANEL_WIDTH, content_height, y_offset)?;
+        self.draw_left_panel(out, x_offset, Layout::LEFT_PANEL_WIDTH, content_height, y_offset)?;
 
         // Draw vertical separator between panels
-        self.draw_vertical_line(&mut stdout, Layout::LEFT_PANEL_WIDTH + x_offset, Layout::HEADER_HEIGHT + y_offset, content_height - Layout::STATUS_BAR_LINES - 2)?;
-
-        // Draw results panel
-        self.draw_results_panel(&mut stdout, Layout::RESULTS_X_OFFSET + x_offset, term_width, content_height, y_offset)?;
+        self.draw_vertical_line(out, Layout::LEFT_PANEL_WIDTH + x_offset, Layout::HEADER_HEIGHT + y_offset, content_height - Layout::STATUS_BAR_LINES - 2)?;
+
+        // Draw results panel, with the detail pane under it when enabled
+        let detail_height = self.detail_height();
+        self.draw_results_panel(out, Layout::RESULTS_X_OFFSET + x_offset, term_width, content_height - detail_height, y_offset)?;
+        if detail_height > 0 {
+            let detail_y = content_height - detail_height + y_offset - Layout::STATUS_BAR_LINES + 1;
+            self.draw_detail_pane(out, Layout::RESULTS_X_OFFSET + x_offset, term_width, detail_y)?;
+        }
 
         // Draw status bars (navigation help + status message)
-        self.draw_status_bars(&mut stdout, x_offset, content_width, content_height, y_offset)?;
+        self.draw_status_bars(out, x_offset, content_width, content_height, y_offset)?;
 
-        stdout.flush()?;
+        out.flush()?;
         Ok(())
     }
 
-    fn draw_search_bar(&self, stdout: &mut io::Stdout, x: u16, width: u16, y: u16) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_search_bar<W: Write>(&self, out: &mut W, x: u16, width: u16, y: u16) -> Result<(), Box<dyn std::error::Error>> {
         let active = matches!(self.active_panel, Panel::Search);
         let border_color = if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM };
 
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, y),
             SetForegroundColor(border_color),
             Print("┌"),
@@ -457,42 +893,62 @@ impl App {
             SetForegroundColor(DraculaTheme::FG),
         )?;
 
-        if active {
+        // Naming a saved search takes over the bar from any panel
+        let typing = active || self.prompt.is_some();
+        if typing {
             queue!(
-                stdout,
+                out,
                 SetForegroundColor(DraculaTheme::PINK),
                 Print("▶ "),
             )?;
         } else {
-            queue!(stdout, Print("  "))?;
+            queue!(out, Print("  "))?;
         }
 
+        // Reverse search replaces the label with the needle being typed
+        let (label, text) = match (&self.prompt, &self.recall) {
+            (Some(prompt), _) => (prompt.label().to_string(), prompt.text()),
+            (None, Some(Recall::Search { needle, .. })) if active => (format!("(reverse-i-search)`{}': ", needle), &self.query),
+            _ => ("Search: ".to_string(), &self.query),
+        };
+
         queue!(
-            stdout,
+            out,
             SetForegroundColor(DraculaTheme::FG),
-            Print("Search: "),
+            Print(&label),
             SetForegroundColor(DraculaTheme::CYAN),
-            Print(&self.query),
+            Print(text),
         )?;
 
         // Add cursor indicator when active
-        if active {
-            queue!(stdout, SetForegroundColor(DraculaTheme::YELLOW), Print("_"))?;
+        if typing {
+            queue!(out, SetForegroundColor(DraculaTheme::YELLOW), Print("_"))?;
         }
 
+        // While recalling, show where in the history we are on the right;
+        // while prompting, what is expected
+        let hint = match (&self.prompt, &self.recall) {
+            (Some(prompt), _) => prompt.hint(),
+            (None, Some(Recall::Browse { draft, index })) if active => self.recall_hint(draft, *index),
+            (None, Some(Recall::Search { needle, index, .. })) if active => self.recall_hint(needle, *index),
+            _ => String::new(),
+        };
+
         // Fill rest of line to align with frame
-        // Printed so far at position x: "│ " (2) + arrow/space (2) + "Search: " (8) + query + maybe "_" (1)
+        // Printed so far at position x: "│ " (2) + arrow/space (2) + label + query + maybe "_" (1)
         // Right border should be at position (x + width - 1)
-        // Current position = x + 2 + 2 + 8 + query + cursor
-        // Padding needed = (x + width - 1) - current_position
-        let used = 2 + 2 + 8 + self.query.chars().count() + (if active { 1 } else { 0 });
+        // Padding needed = (x + width - 1) - current_position - hint
+        let used = 2 + 2 + label.chars().count() + text.chars().count() + (if typing { 1 } else { 0 });
         let right_border_pos = (x as usize) + (width as usize) - 1;
         let current_pos = (x as usize) + used;
         let remaining = right_border_pos.saturating_sub(current_pos);
+        let hint = if hint.chars().count() + 1 < remaining { hint } else { String::new() };
         queue!(
-            stdout,
+            out,
             SetForegroundColor(DraculaTheme::FG),
-            Print(" ".repeat(remaining)),
+            Print(" ".repeat(remaining - hint.chars().count().min(remaining))),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print(&hint),
             SetForegroundColor(border_color),
             Print("│"),
         )?;
@@ -500,9 +956,21 @@ impl App {
         Ok(())
     }
 
-    fn draw_horizontal_line(&self, stdout: &mut io::Stdout, x: u16, y: u16, width: u16) -> Result<(), Box<dyn std::error::Error>> {
+    /// "history 2/15 · 42 results " for the recalled entry
+    fn recall_hint(&self, needle: &str, index: usize) -> String {
+        let matches = self.history.matches(needle);
+        match matches.get(index) {
+            Some(entry) => {
+                let count = entry.result_count.map(|n| format!(" · {} results", n)).unwrap_or_default();
+                format!("history {}/{}{} ", index + 1, matches.len(), count)
+            }
+            None => "no match ".to_string(),
+        }
+    }
+
+    fn draw_horizontal_line<W: Write>(&self, out: &mut W, x: u16, y: u16, width: u16) -> Result<(), Box<dyn std::error::Error>> {
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, y),
             SetForegroundColor(DraculaTheme::FG_DIM),
             Print("├"),
@@ -512,10 +980,10 @@ impl App {
         Ok(())
     }
 
-    fn draw_vertical_line(&self, stdout: &mut io::Stdout, x: u16, start_y: u16, height: u16) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_vertical_line<W: Write>(&self, out: &mut W, x: u16, start_y: u16, height: u16) -> Result<(), Box<dyn std::error::Error>> {
         for y in start_y..(start_y + height) {
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x, y),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│"),
@@ -524,7 +992,13 @@ impl App {
         Ok(())
     }
 
-    fn draw_left_panel(&self, stdout: &mut io::Stdout, x: u16, _width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_left_panel<W: Write>(&self, out: &mut W, x: u16, _width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+        match self.active_panel {
+            Panel::Saved => return self.draw_saved_panel(out, x, height, y_offset),
+            Panel::Shows => return self.draw_shows_panel(out, x, height, y_offset),
+            _ => {}
+        }
+
         let active = matches!(self.active_panel, Panel::Filters);
         let mut y = Layout::HEADER_HEIGHT + y_offset;
 
@@ -532,7 +1006,7 @@ impl App {
         let panel_height = height + y_offset - Layout::STATUS_BAR_LINES - y;
         for i in 0..panel_height {
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x, y + i),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│"),
@@ -543,35 +1017,36 @@ impl App {
         // Format: "┌─ SORT BY {:─<N}┐" where N makes total = 21
         // "┌─ SORT BY " = 11 chars, "┐" = 1 char, so N = 21 - 12 = 9
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::PURPLE }),
             Print(format!("┌─ SORT BY {:─<9}┐", "")),
         )?;
         y += 1;
 
-        let sorts = vec![
-            ("Seeders", SortMode::Seeders),
-            ("Size", SortMode::Size),
-            ("Name", SortMode::Name),
+        // Key, direction and tie-breaker; Space cycles or toggles each
+        let sort = self.filters.sort;
+        let sorts = [
+            format!("Sort  {}", sort.key.label()),
+            format!("Order {}", if sort.descending { "↓ desc" } else { "↑ asc" }),
+            format!("Then  {}", sort.then_by.map_or("-", |key| key.label())),
         ];
 
-        for (i, (name, mode)) in sorts.iter().enumerate() {
-            let selected = self.sort_by == *mode;
+        for (i, content) in sorts.iter().enumerate() {
             let cursor = active && self.sort_cursor == i;
+            let unset = i == 2 && sort.then_by.is_none();
 
-            let (fg, bg, marker) = if cursor {
-                (DraculaTheme::BG, DraculaTheme::PINK, "●")
-            } else if selected {
-                (DraculaTheme::GREEN, DraculaTheme::BG, "●")
+            let (fg, bg) = if cursor {
+                (DraculaTheme::BG, DraculaTheme::PINK)
+            } else if unset {
+                (DraculaTheme::FG_DIM, DraculaTheme::BG)
             } else {
-                (DraculaTheme::FG_DIM, DraculaTheme::BG, "○")
+                (DraculaTheme::GREEN, DraculaTheme::BG)
             };
 
             // Content rows: "│ " + content + " │"
-            let content = format!("{} {}", marker, name);
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x + 1, y),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│ "),
@@ -587,7 +1062,7 @@ impl App {
 
         // Footer: "└{:─<19}┘" = 21 total
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM }),
             Print(format!("└{:─<19}┘", "")),
@@ -597,7 +1072,7 @@ impl App {
         // Indexers section
         // "┌─ INDEXERS " = 12 chars, "┐" = 1 char, so N = 21 - 13 = 8
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::PURPLE }),
             Print(format!("┌─ INDEXERS {:─<8}┐", "")),
@@ -606,7 +1081,7 @@ impl App {
 
         let visible_indexers = ((height as usize).saturating_sub(y as usize + 8)).min(self.available_indexers.len());
         for (i, indexer) in self.available_indexers.iter().take(visible_indexers).enumerate() {
-            let selected = self.selected_indexers.contains(indexer);
+            let selected = self.filters.indexers.contains(indexer);
             let cursor = active && self.sort_cursor == i + 3;
 
             let (fg, bg, marker) = if cursor {
@@ -617,16 +1092,36 @@ impl App {
                 (DraculaTheme::FG_DIM, DraculaTheme::BG, "[ ]")
             };
 
-            // Content rows: "│ " + content + " │"
-            let content = format!("{} {}", marker, indexer);
+            // Content rows: "│ " + content + " │", with the indexer's
+            // progress in the last search right-aligned, cutting long names
+            let (status, status_color) = self.indexer_status_label(indexer).unwrap_or((String::new(), fg));
+            let status_width = status.chars().count();
+            let name_width = Layout::FILTER_BOX_CONTENT_WIDTH - 4 - if status.is_empty() { 0 } else { status_width + 1 };
+            let name = if indexer.chars().count() > name_width {
+                format!("{}…", indexer.chars().take(name_width.saturating_sub(1)).collect::<String>())
+            } else {
+                indexer.clone()
+            };
+            let content = format!("{} {:<width$}", marker, name, width = name_width);
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x + 1, y),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│ "),
                 SetBackgroundColor(bg),
                 SetForegroundColor(fg),
-                Print(format!("{:<width$}", content, width = Layout::FILTER_BOX_CONTENT_WIDTH)),
+                Print(content),
+            )?;
+            if !status.is_empty() {
+                queue!(
+                    out,
+                    Print(" "),
+                    SetForegroundColor(if cursor { fg } else { status_color }),
+                    Print(status),
+                )?;
+            }
+            queue!(
+                out,
                 SetBackgroundColor(DraculaTheme::BG),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print(" │"),
@@ -635,42 +1130,49 @@ impl App {
         }
 
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM }),
             Print(format!("└{:─<19}┘", "")),
         )?;
         y += 2;
 
-        // Min seeds section
-        // "┌─ MIN SEEDS " = 13 chars, "┐" = 1 char, so N = 21 - 14 = 7
+        // Limits section: seed and size bounds, edited in place
+        // "┌─ LIMITS " = 10 chars, "┐" = 1 char, so N = 21 - 11 = 10
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::PURPLE }),
-            Print(format!("┌─ MIN SEEDS {:─<7}┐", "")),
+            Print(format!("┌─ LIMITS {:─<10}┐", "")),
         )?;
         y += 1;
 
-        let min_seed_options = vec![0, 5, 10, 100];
         let total_items = 3 + self.available_indexers.len();
 
-        for (i, &seeds) in min_seed_options.iter().enumerate() {
-            let selected = self.min_seeds == seeds;
+        for (i, limit) in Limit::ALL.iter().enumerate() {
             let cursor = active && self.sort_cursor == total_items + i;
-
-            let (fg, bg, marker) = if cursor {
-                (DraculaTheme::BG, DraculaTheme::PINK, "●")
-            } else if selected {
-                (DraculaTheme::GREEN, DraculaTheme::BG, "●")
+            let editing = cursor && self.limit_input.is_some();
+            let value = self.limit_value(*limit);
+
+            let (fg, bg) = if editing && self.limit_error {
+                (DraculaTheme::BG, DraculaTheme::RED)
+            } else if editing {
+                (DraculaTheme::BG, DraculaTheme::CYAN)
+            } else if cursor {
+                (DraculaTheme::BG, DraculaTheme::PINK)
+            } else if value.is_some() {
+                (DraculaTheme::GREEN, DraculaTheme::BG)
             } else {
-                (DraculaTheme::FG_DIM, DraculaTheme::BG, "○")
+                (DraculaTheme::FG_DIM, DraculaTheme::BG)
             };
 
             // Content rows: "│ " + content + " │"
-            let content = format!("{} {} seeds", marker, seeds);
+            let content = match (&self.limit_input, editing) {
+                (Some(input), true) => format!("{:<9}{:>7}_", limit.label(), input),
+                _ => format!("{:<9}{:>8}", limit.label(), value.unwrap_or_else(|| "-".to_string())),
+            };
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x + 1, y),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│ "),
@@ -685,7 +1187,7 @@ impl App {
         }
 
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM }),
             Print(format!("└{:─<19}┘", "")),
@@ -695,18 +1197,18 @@ impl App {
         // NSFW Filter section
         // "┌─ NSFW " = 8 chars, "┐" = 1 char, so N = 21 - 9 = 12
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::PURPLE }),
             Print(format!("┌─ NSFW {:─<12}┐", "")),
         )?;
         y += 1;
 
-        let nsfw_options = vec![("Filter NSFW", true), ("Allow NSFW", false)];
-        let nsfw_base = total_items + 4; // After sort, indexers, and min_seeds
+        let nsfw_options = [("Filter NSFW", true), ("Allow NSFW", false)];
+        let nsfw_base = total_items + Limit::ALL.len(); // After sort, indexers, and limits
 
         for (i, (label, value)) in nsfw_options.iter().enumerate() {
-            let selected = self.filter_nsfw == *value;
+            let selected = self.filters.filter_nsfw == *value;
             let cursor = active && self.sort_cursor == nsfw_base + i;
 
             let (fg, bg, marker) = if cursor {
@@ -720,7 +1222,7 @@ impl App {
             // Content rows: "│ " + content + " │"
             let content = format!("{} {}", marker, label);
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x + 1, y),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│ "),
@@ -736,17 +1238,171 @@ impl App {
 
         // NSFW section bottom border
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x + 1, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM }),
             Print(format!("└{:─<19}┘", "")),
         )?;
-        y += 1;
 
         // Draw outer bottom border (for the entire left panel)
         let bottom_y = height + y_offset - Layout::STATUS_BAR_LINES;
         queue!(
-            stdout,
+            out,
+            cursor::MoveTo(x, bottom_y),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print("└"),
+            Print("─".repeat(Layout::LEFT_PANEL_WIDTH as usize - 1)),
+            Print("┘"),
+        )?;
+
+        Ok(())
+    }
+
+    /// How a Filters panel indexer is doing in the last search. "all" shows
+    /// how many indexers have answered while the search runs.
+    fn indexer_status_label(&self, indexer: &str) -> Option<(String, Color)> {
+        let spinner = ['|', '/', '-', '\\'][self.spinner_frame as usize % 4];
+        if let Some(status) = self.indexer_status.get(self.registry.api_id(indexer)) {
+            return Some(status.label(spinner));
+        }
+
+        let searching = self.searching || self.revalidating;
+        if indexer != "all" || !searching || !self.filters.indexers.iter().any(|i| i == "all") {
+            return None;
+        }
+        let answered = self.indexer_status.values().filter(|s| !matches!(s, IndexerStatus::Pending)).count();
+        Some((format!("{}/{}", answered, self.indexer_status.len()), DraculaTheme::COMMENT))
+    }
+
+    /// The sidebar while the Saved panel is focused: saved searches with
+    /// how many results their last run turned up that were new
+    fn draw_saved_panel<W: Write>(&self, out: &mut W, x: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+        let searches = self.saved.list();
+        let lines: Vec<SidebarLine> = if searches.is_empty() {
+            ["Nothing saved yet.", "Ctrl-S saves the", "current search."].iter()
+                .map(|line| (line.to_string(), String::new(), DraculaTheme::FG_DIM))
+                .collect()
+        } else {
+            searches.iter()
+                .map(|search| match (search.last_run, search.new.len()) {
+                    (_, new) if new > 0 => (search.name.clone(), format!("+{}", new), DraculaTheme::GREEN),
+                    (Some(_), _) => (search.name.clone(), String::new(), DraculaTheme::FG),
+                    (None, _) => (search.name.clone(), String::new(), DraculaTheme::FG_DIM),
+                })
+                .collect()
+        };
+
+        // Last run of the highlighted search
+        let note = searches.get(self.saved_cursor)
+            .map(|search| search.last_run.map_or_else(|| "never run".to_string(), |t| format!("ran {}", format_age(t))));
+
+        let cursor = (!searches.is_empty()).then_some(self.saved_cursor);
+        self.draw_sidebar_list(out, x, height, y_offset, "SAVED", &lines, cursor, note)
+    }
+
+    /// The sidebar while the Shows panel is focused: tracked shows with
+    /// their next expected episode
+    fn draw_shows_panel<W: Write>(&self, out: &mut W, x: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+        let shows = self.series.list();
+        let lines: Vec<SidebarLine> = if shows.is_empty() {
+            ["No shows tracked.", "Press a to add one", "(e.g. Name S02E04)"].iter()
+                .map(|line| (line.to_string(), String::new(), DraculaTheme::FG_DIM))
+                .collect()
+        } else {
+            shows.iter()
+                .map(|show| (show.name.clone(), show.next_label(), DraculaTheme::FG))
+                .collect()
+        };
+
+        // What the highlighted show is doing, or when it was last sent
+        let note = shows.get(self.series_cursor).map(|show| {
+            let spinner = ['|', '/', '-', '\\'][self.spinner_frame as usize % 4];
+            match (&self.grabbing, &self.series_note) {
+                (Some(name), _) if *name == show.name => format!("Searching {} {}", show.next_label(), spinner),
+                (_, Some((name, note))) if *name == show.name => note.clone(),
+                _ => show.last_sent_at.map_or_else(|| "nothing sent yet".to_string(), |t| format!("sent {}", format_age(t))),
+            }
+        });
+
+        let cursor = (!shows.is_empty()).then_some(self.series_cursor);
+        self.draw_sidebar_list(out, x, height, y_offset, "SHOWS", &lines, cursor, note)
+    }
+
+    /// A boxed list filling the left panel, with a tag on the right of each
+    /// line and a note under the box
+    #[allow(clippy::too_many_arguments)]
+    fn draw_sidebar_list<W: Write>(&self, out: &mut W, x: u16, height: u16, y_offset: u16, title: &str, lines: &[SidebarLine], cursor: Option<usize>, note: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+        let mut y = Layout::HEADER_HEIGHT + y_offset;
+        let bottom_y = height + y_offset - Layout::STATUS_BAR_LINES;
+
+        for i in 0..bottom_y - y {
+            queue!(
+                out,
+                cursor::MoveTo(x, y + i),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print("│"),
+            )?;
+        }
+
+        // "┌─ TITLE " = 4 + title chars, "┐" = 1 char, so N = 21 - 5 - title
+        queue!(
+            out,
+            cursor::MoveTo(x + 1, y),
+            SetForegroundColor(DraculaTheme::CYAN),
+            Print(format!("┌─ {} {}┐", title, "─".repeat(16usize.saturating_sub(title.chars().count())))),
+        )?;
+        y += 1;
+
+        // Keep the cursor in view when there are more lines than rows
+        let visible = (bottom_y.saturating_sub(y + 2)) as usize;
+        let skip = cursor.map_or(0, |cursor| (cursor + 1).saturating_sub(visible));
+
+        for (i, (label, tag, color)) in lines.iter().enumerate().skip(skip).take(visible) {
+            let (fg, bg) = if cursor == Some(i) { (DraculaTheme::BG, DraculaTheme::PINK) } else { (*color, DraculaTheme::BG) };
+
+            // Content rows: "│ " + label + tag + " │"
+            let tag: String = tag.chars().take(7).collect();
+            let tag_width = tag.chars().count() + usize::from(!tag.is_empty());
+            let label_width = Layout::FILTER_BOX_CONTENT_WIDTH - tag_width;
+            let label: String = if label.chars().count() > label_width {
+                label.chars().take(label_width.saturating_sub(1)).chain(std::iter::once('…')).collect()
+            } else {
+                label.clone()
+            };
+            queue!(
+                out,
+                cursor::MoveTo(x + 1, y),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print("│ "),
+                SetBackgroundColor(bg),
+                SetForegroundColor(fg),
+                Print(format!("{:<label_width$}{:>tag_width$}", label, tag)),
+                SetBackgroundColor(DraculaTheme::BG),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print(" │"),
+            )?;
+            y += 1;
+        }
+
+        queue!(
+            out,
+            cursor::MoveTo(x + 1, y),
+            SetForegroundColor(DraculaTheme::CYAN),
+            Print(format!("└{:─<19}┘", "")),
+        )?;
+
+        if let Some(note) = note {
+            let note: String = note.chars().take(19).collect();
+            queue!(
+                out,
+                cursor::MoveTo(x + 2, y + 1),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print(note),
+            )?;
+        }
+
+        queue!(
+            out,
             cursor::MoveTo(x, bottom_y),
             SetForegroundColor(DraculaTheme::FG_DIM),
             Print("└"),
@@ -757,38 +1413,54 @@ impl App {
         Ok(())
     }
 
-    fn draw_results_panel(&self, stdout: &mut io::Stdout, x: u16, width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+    fn draw_results_panel<W: Write>(&self, out: &mut W, x: u16, width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
         let active = matches!(self.active_panel, Panel::Results);
         let y = Layout::HEADER_HEIGHT + y_offset;
 
         // Calculate scroll state upfront
         let has_more_above = self.scroll_offset > 0;
         let results_height = (height as usize).saturating_sub(y as usize + Layout::STATUS_BAR_LINES as usize + 2);
-        let visible_end = self.scroll_offset + results_height.min(self.results.len() - self.scroll_offset);
-        let has_more_below = visible_end < self.results.len();
+        let rows = self.rows();
+        let visible_end = self.scroll_offset + results_height.min(rows.len().saturating_sub(self.scroll_offset));
+        let has_more_below = visible_end < rows.len();
 
         // Results header - spans from x to right margin
         // Right edge is at (width - MARGIN_X - 1), so header_width = right_edge - x - 11 ("┌─ RESULTS ")
+        // minus the active quality profile's name and how old cached results are
         let right_edge = (width as usize).saturating_sub(Layout::MARGIN_X as usize + 1);
-        let header_width = right_edge.saturating_sub(x as usize + 11);
+        let profile_label = self.profile().map(|p| format!("· {} ", p.name)).unwrap_or_default();
+        let cache_label = match (self.cached_at, self.config.cache.offline) {
+            (Some(at), offline) => format!(
+                "· {}cached {}{} ",
+                if offline { "offline, " } else { "" },
+                format_age(at),
+                if self.revalidating { " ↻" } else { "" },
+            ),
+            (None, true) => "· offline ".to_string(),
+            (None, false) => String::new(),
+        };
+        let header_width = right_edge.saturating_sub(x as usize + 11 + profile_label.chars().count() + cache_label.chars().count());
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, y),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::PURPLE }),
             Print("┌─ RESULTS "),
+            SetForegroundColor(DraculaTheme::YELLOW),
+            Print(&profile_label),
+            SetForegroundColor(DraculaTheme::COMMENT),
+            Print(&cache_label),
             SetForegroundColor(DraculaTheme::FG_DIM),
             Print("─".repeat(header_width)),
             Print("┐"),
         )?;
 
-        // Calculate content dimensions
-        let right_border_col = (width as usize).saturating_sub(Layout::MARGIN_X as usize + 1);
-
-        if self.searching || self.sending_to_putio {
+        // The spinner box only stays up until the first rows stream in
+        let fetching = self.searching && self.results.is_empty();
+        if fetching || self.sending_to_putio {
             // Draw outer panel borders
             for row_y in (y + 1)..(height + y_offset - Layout::STATUS_BAR_LINES) {
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(x, row_y),
                     SetForegroundColor(DraculaTheme::FG_DIM),
                     Print("│"),
@@ -798,11 +1470,11 @@ impl App {
             }
 
             // Draw a nice centered box
-            if self.searching {
+            if fetching {
                 // Spinner animation for searching
                 let spinner_chars = ['|', '/', '-', '\\'];
-                let spinner = spinner_chars[(self.spinner_frame / 1) as usize % 4];
-                let message = format!("Fetching {}", spinner);
+                let spinner = spinner_chars[self.spinner_frame as usize % 4];
+                let message = format!("Fetching {}  ESC cancels", spinner);
 
                 let box_width = message.len() + 4; // 2 chars padding on each side
                 let panel_width = (width as usize).saturating_sub(x as usize + 1);
@@ -811,7 +1483,7 @@ impl App {
 
                 // Top border
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("┌"),
@@ -822,7 +1494,7 @@ impl App {
                 // Content
                 let padding = (box_width - 2).saturating_sub(message.len()) / 2;
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y + 1),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("│"),
@@ -838,7 +1510,7 @@ impl App {
 
                 // Bottom border
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y + 2),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("└"),
@@ -848,10 +1520,10 @@ impl App {
             } else if self.sending_to_putio {
                 // Sending confirmation with spinner or checkmark
                 let icon = if self.sending_complete {
-                    "✓"
+                    if self.send_failed { "✗" } else { "✓" }
                 } else {
                     let spinner_chars = ['|', '/', '-', '\\'];
-                    let ch = spinner_chars[(self.spinner_frame / 1) as usize % 4];
+                    let ch = spinner_chars[self.spinner_frame as usize % 4];
                     &format!("{}", ch)[..]
                 };
 
@@ -867,7 +1539,7 @@ impl App {
 
                 // Top border
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("┌"),
@@ -880,13 +1552,17 @@ impl App {
                 let padding = (box_width - 2).saturating_sub(msg_len) / 2;
                 let right_padding = (box_width - 2).saturating_sub(msg_len + padding);
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y + 1),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("│"),
                     SetForegroundColor(DraculaTheme::FG),
                     Print(" ".repeat(padding)),
-                    SetForegroundColor(if self.sending_complete { DraculaTheme::GREEN } else { DraculaTheme::CYAN }),
+                    SetForegroundColor(match (self.sending_complete, self.send_failed) {
+                        (false, _) => DraculaTheme::CYAN,
+                        (true, false) => DraculaTheme::GREEN,
+                        (true, true) => DraculaTheme::RED,
+                    }),
                     Print(&message),
                     SetForegroundColor(DraculaTheme::FG),
                     Print(" ".repeat(right_padding)),
@@ -896,7 +1572,7 @@ impl App {
 
                 // Bottom border
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(box_x, box_y + 2),
                     SetForegroundColor(DraculaTheme::CYAN),
                     Print("└"),
@@ -905,11 +1581,15 @@ impl App {
                 )?;
             }
         } else if self.results.is_empty() {
-            let message = "No results. Press Enter to search.";
+            let message = if self.fuzzy_query.is_empty() || self.raw_results.is_empty() {
+                "No results. Press Enter to search."
+            } else {
+                "Nothing matches the filter. ESC clears it."
+            };
 
             // Draw y+1 row with borders only
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x, y + 1),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│"),
@@ -919,7 +1599,7 @@ impl App {
 
             // Draw message row at y+2 - left-aligned with right border
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x, y + 2),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│   "),
@@ -932,7 +1612,7 @@ impl App {
             // Fill empty rows with borders (starting from y+3)
             for row_y in (y + 3)..(height + y_offset - Layout::STATUS_BAR_LINES) {
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(x, row_y),
                     SetForegroundColor(DraculaTheme::FG_DIM),
                     Print("│"),
@@ -946,7 +1626,7 @@ impl App {
             // Draw left and right borders for all rows when showing results
             for row_y in (y + 1)..(height + y_offset - Layout::STATUS_BAR_LINES) {
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(x, row_y),
                     SetForegroundColor(DraculaTheme::FG_DIM),
                     Print("│"),
@@ -957,7 +1637,7 @@ impl App {
 
             // Column headers with scroll indicator
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x, y + 1),
                 SetForegroundColor(DraculaTheme::FG_DIM),
                 Print("│ "),
@@ -965,12 +1645,12 @@ impl App {
 
             if has_more_above {
                 queue!(
-                    stdout,
+                    out,
                     SetForegroundColor(DraculaTheme::YELLOW),
                     Print("^^ "),
                 )?;
             } else {
-                queue!(stdout, SetForegroundColor(DraculaTheme::FG), Print("   "))?;
+                queue!(out, SetForegroundColor(DraculaTheme::FG), Print("   "))?;
             }
 
             // Use cached layout positions (recalculated only on resize)
@@ -981,66 +1661,136 @@ impl App {
             let sep_pos = cache.separator_column;
             let title_width = cache.title_width;
 
+            // The sorted column gets an arrow; keys without a column of
+            // their own are named after the Title header instead
+            let sort = self.filters.sort;
+            let arrow = if sort.descending { "↓" } else { "↑" };
+            let sorted = |key: SortMode| sort.key == key;
+            let release_sorted = sorted(SortMode::Resolution) && cache.release_column.is_some();
+            let title_header = if sorted(SortMode::Name) {
+                format!("Title {}", arrow)
+            } else if matches!(sort.key, SortMode::Leechers | SortMode::Ratio)
+                || (sorted(SortMode::Resolution) && !release_sorted)
+            {
+                format!("Title  (by {} {})", sort.key.label().to_lowercase(), arrow)
+            } else {
+                "Title".to_string()
+            };
+
             // Print left side (Sel and Title)
             queue!(
-                stdout,
+                out,
                 SetForegroundColor(DraculaTheme::CYAN),
-                Print("Sel │ Title"),
+                Print("Sel │ "),
+                Print(title_header),
             )?;
 
             // Add separator before Size column
             queue!(
-                stdout,
-                cursor::MoveTo(sep_pos as u16, y + 1),
+                out,
+                cursor::MoveTo(sep_pos, y + 1),
                 SetForegroundColor(DraculaTheme::CYAN),
                 Print(" │ "),
             )?;
 
+            // Release column header and its separator before Size
+            if let Some(release_start) = cache.release_column {
+                queue!(
+                    out,
+                    cursor::MoveTo(release_start, y + 1),
+                    SetForegroundColor(DraculaTheme::CYAN),
+                    Print(format!(
+                        "{:<width$} │ ",
+                        if release_sorted { format!("Release {}", arrow) } else { "Release".to_string() },
+                        width = Layout::RELEASE_COLUMN_WIDTH
+                    )),
+                )?;
+            }
+
+            if let Some(score_start) = cache.score_column {
+                queue!(
+                    out,
+                    cursor::MoveTo(score_start, y + 1),
+                    SetForegroundColor(DraculaTheme::CYAN),
+                    Print("Score │ "),
+                )?;
+            }
+
             // Position and print Size column
             queue!(
-                stdout,
-                cursor::MoveTo(size_start as u16, y + 1),
+                out,
+                cursor::MoveTo(size_start, y + 1),
                 SetForegroundColor(DraculaTheme::CYAN),
-                Print(format!("{:^12} │ ", "Size")),
+                Print(format!("{:^12} │ ", if sorted(SortMode::Size) { format!("Size {}", arrow) } else { "Size".to_string() })),
             )?;
 
             // Position and print Seeds column
             queue!(
-                stdout,
-                cursor::MoveTo(seeds_start as u16, y + 1),
+                out,
+                cursor::MoveTo(seeds_start, y + 1),
                 SetForegroundColor(DraculaTheme::CYAN),
-                Print(format!("{:^5} │ ", "Seeds")),
+                Print(format!("{:^5} │ ", if sorted(SortMode::Seeders) { format!("Seed{}", arrow) } else { "Seeds".to_string() })),
             )?;
 
             // Position and print Source column
             queue!(
-                stdout,
-                cursor::MoveTo(source_start as u16, y + 1),
+                out,
+                cursor::MoveTo(source_start, y + 1),
                 SetForegroundColor(DraculaTheme::CYAN),
-                Print(format!("{:^10}", "Source")),
+                Print(format!("{:^10}", if sorted(SortMode::Indexer) { format!("Source {}", arrow) } else { "Source".to_string() })),
             )?;
 
             // Results list
-            for (i, result) in self.results[self.scroll_offset..visible_end].iter().enumerate() {
+            for (i, &row) in rows[self.scroll_offset..visible_end].iter().enumerate() {
+                let result = self.row_result(row);
                 let actual_index = self.scroll_offset + i;
                 let is_selected = actual_index == self.selected_index;
                 let is_marked = result.selected;
+                // `None` inside means the active profile rejects the result
+                let score = self.profile().map(|profile| profile.score(result));
+                let is_suspicious = !suspicions(result).is_empty();
 
                 let (fg, bg) = if is_selected && active {
                     (DraculaTheme::BG, DraculaTheme::PINK)
                 } else if is_marked {
                     (DraculaTheme::GREEN, DraculaTheme::BG)
+                } else if is_suspicious {
+                    (DraculaTheme::ORANGE, DraculaTheme::BG)
+                } else if score == Some(None) {
+                    (DraculaTheme::FG_DIM, DraculaTheme::BG)
                 } else {
                     (DraculaTheme::FG, DraculaTheme::BG)
                 };
 
                 let checkbox = if is_marked { "[✓]" } else { "[ ]" };
 
+                // Results the last saved search run hadn't seen before
+                let is_new = matches!(row, Row::Result(_))
+                    && !self.new_hashes.is_empty()
+                    && result.infohash().is_some_and(|hash| self.new_hashes.contains(&hash));
+
+                // Merged groups get an expand marker, their copies are indented under them
+                let full_title = match row {
+                    Row::Result(index) if !result.sources.is_empty() => {
+                        let marker = if self.expanded.contains(&index) { "▾" } else { "▸" };
+                        format!("{} {}", marker, result.title)
+                    }
+                    Row::Result(_) => result.title.clone(),
+                    Row::Source(..) => format!("  └ {}", result.title),
+                };
+                // Without a release column the NEW badge goes in front of the title
+                let full_title = if is_new && cache.release_column.is_none() {
+                    format!("NEW {}", full_title)
+                } else {
+                    full_title
+                };
+                let full_title = if is_suspicious { format!("⚠ {}", full_title) } else { full_title };
+
                 // Scrolling title logic for long titles - only scroll when highlighted
-                let title = if result.title.chars().count() > title_width {
+                let title = if full_title.chars().count() > title_width {
                     if is_selected && active {
                         // OPTIMIZED: Precompute chars for O(1) access instead of O(n)
-                        let extended_title = format!("{}    ", result.title);
+                        let extended_title = format!("{}    ", full_title);
                         let title_chars: Vec<char> = extended_title.chars().collect();
                         let scroll_pos = self.title_scroll_offset % title_chars.len();
 
@@ -1052,25 +1802,33 @@ impl App {
                             .collect()
                     } else {
                         // Not selected: just truncate with ellipsis (char-safe)
-                        let truncated: String = result.title.chars().take(title_width.saturating_sub(3)).collect();
+                        let truncated: String = full_title.chars().take(title_width.saturating_sub(3)).collect();
                         format!("{}...", truncated)
                     }
                 } else {
-                    format!("{:<width$}", result.title, width = title_width)
+                    format!("{:<width$}", full_title, width = title_width)
                 };
 
-                // Map indexer name and truncate if needed
-                let indexer_lower = result.indexer.to_lowercase();
-                let indexer_display = if indexer_lower.contains("rutracker") {
-                    "RUtracker"
+                // Characters matched by the `/` filter, as positions in `title`.
+                // The scrolling highlighted row is left plain.
+                let full_len = full_title.chars().count();
+                let visible_len = if full_len > title_width { title_width.saturating_sub(3) } else { full_len };
+                let highlights: Vec<usize> = if self.fuzzy_query.is_empty() || (is_selected && active) {
+                    Vec::new()
                 } else {
-                    match result.indexer.as_str() {
-                        "thepiratebay" | "The Pirate Bay" => "TPB",
-                        "eztv" => "EZTV",
-                        "therarbg" => "RARBG",
-                        "yts" => "YTS",
-                        _ => &result.indexer,
-                    }
+                    let prefix = full_len - result.title.chars().count();
+                    fuzzy_match(&self.fuzzy_query, &self.fuzzy_text(result))
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|i| i + prefix)
+                        .filter(|&i| i < visible_len)
+                        .collect()
+                };
+
+                // Map indexer name (plus how many others carry it) and truncate if needed
+                let indexer_display = match result.sources.len() {
+                    0 | 1 => self.registry.display_name(&result.indexer).to_string(),
+                    n => format!("{} +{}", self.registry.display_name(&result.indexer), n - 1),
                 };
 
                 let indexer = if indexer_display.chars().count() > 10 {
@@ -1085,7 +1843,7 @@ impl App {
 
                 // Print left side (checkbox and title with scroll indicator space)
                 queue!(
-                    stdout,
+                    out,
                     cursor::MoveTo(x, row_y),
                     SetForegroundColor(DraculaTheme::FG_DIM),
                     Print("│ "),
@@ -1095,22 +1853,70 @@ impl App {
                     SetForegroundColor(fg),
                     Print(&checkbox),
                     Print(" │ "),
-                    Print(&title),
-                    SetBackgroundColor(DraculaTheme::BG),
                 )?;
+                if highlights.is_empty() {
+                    queue!(out, Print(&title))?;
+                } else {
+                    for (i, c) in title.chars().enumerate() {
+                        let color = if highlights.contains(&i) { DraculaTheme::YELLOW } else { fg };
+                        queue!(out, SetForegroundColor(color), Print(c))?;
+                    }
+                }
+                queue!(out, SetBackgroundColor(DraculaTheme::BG))?;
 
                 // Add separator before Size column
                 queue!(
-                    stdout,
-                    cursor::MoveTo(sep_pos as u16, row_y),
+                    out,
+                    cursor::MoveTo(sep_pos, row_y),
                     SetForegroundColor(DraculaTheme::FG),
                     Print(" │ "),
                 )?;
 
+                // Release badges, each in its own color unless the row is highlighted
+                if let Some(release_start) = cache.release_column {
+                    queue!(out, cursor::MoveTo(release_start, row_y), SetBackgroundColor(bg))?;
+                    let mut used = 0;
+                    let mut badges = release_badges(&result.release());
+                    if is_new {
+                        badges.insert(0, ("NEW".to_string(), DraculaTheme::YELLOW));
+                    }
+                    for (badge, color) in badges {
+                        let len = badge.chars().count();
+                        if used + len > Layout::RELEASE_COLUMN_WIDTH {
+                            break;
+                        }
+                        let color = if is_selected && active { fg } else { color };
+                        queue!(out, SetForegroundColor(color), Print(&badge))?;
+                        used += len;
+                        if used < Layout::RELEASE_COLUMN_WIDTH {
+                            queue!(out, Print(" "))?;
+                            used += 1;
+                        }
+                    }
+                    queue!(
+                        out,
+                        Print(" ".repeat(Layout::RELEASE_COLUMN_WIDTH - used)),
+                        SetBackgroundColor(DraculaTheme::BG),
+                        SetForegroundColor(DraculaTheme::FG),
+                        Print(" │ "),
+                    )?;
+                }
+
+                if let (Some(score_start), Some(score)) = (cache.score_column, score) {
+                    queue!(
+                        out,
+                        cursor::MoveTo(score_start, row_y),
+                        SetBackgroundColor(bg),
+                        SetForegroundColor(fg),
+                        Print(format!("{:^5} │ ", score.map_or("✗".to_string(), |s| s.to_string()))),
+                        SetBackgroundColor(DraculaTheme::BG),
+                    )?;
+                }
+
                 // Position and print Size column at absolute position
                 queue!(
-                    stdout,
-                    cursor::MoveTo(size_start as u16, row_y),
+                    out,
+                    cursor::MoveTo(size_start, row_y),
                     SetBackgroundColor(bg),
                     SetForegroundColor(fg),
                     Print(format!("{:>12} │ ", result.size_str())),
@@ -1119,8 +1925,8 @@ impl App {
 
                 // Position and print Seeds column at absolute position
                 queue!(
-                    stdout,
-                    cursor::MoveTo(seeds_start as u16, row_y),
+                    out,
+                    cursor::MoveTo(seeds_start, row_y),
                     SetBackgroundColor(bg),
                     SetForegroundColor(fg),
                     Print(format!("{:^5} │ ", result.seeders)),
@@ -1129,8 +1935,8 @@ impl App {
 
                 // Position and print Source column at absolute position
                 queue!(
-                    stdout,
-                    cursor::MoveTo(source_start as u16, row_y),
+                    out,
+                    cursor::MoveTo(source_start, row_y),
                     SetBackgroundColor(bg),
                     SetForegroundColor(fg),
                     Print(format!("{:<10}", indexer)),
@@ -1146,7 +1952,7 @@ impl App {
         let right_border_pos = width - Layout::MARGIN_X - 1;
         let border_width = (right_border_pos as usize).saturating_sub(x as usize + 1);
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, height + y_offset - Layout::STATUS_BAR_LINES),
             SetForegroundColor(if active { DraculaTheme::CYAN } else { DraculaTheme::FG_DIM }),
             Print("└"),
@@ -1154,10 +1960,22 @@ impl App {
             Print("┘"),
         )?;
 
+        // The `/` filter line sits in the bottom border
+        if self.fuzzy_editing || !self.fuzzy_query.is_empty() {
+            let cursor = if self.fuzzy_editing { "_" } else { "" };
+            let line: String = format!(" / {}{} ", self.fuzzy_query, cursor).chars().take(border_width.saturating_sub(2)).collect();
+            queue!(
+                out,
+                cursor::MoveTo(x + 2, height + y_offset - Layout::STATUS_BAR_LINES),
+                SetForegroundColor(DraculaTheme::YELLOW),
+                Print(line),
+            )?;
+        }
+
         // Show vv indicator inside the frame if there's more below
         if !self.results.is_empty() && has_more_below {
             queue!(
-                stdout,
+                out,
                 cursor::MoveTo(x + 2, height - 4),
                 SetForegroundColor(DraculaTheme::YELLOW),
                 Print("vv"),
@@ -1167,9 +1985,133 @@ impl App {
         Ok(())
     }
 
-    fn draw_status_bars(&self, stdout: &mut io::Stdout, x: u16, width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
+    /// Number of result rows the table can show
+    fn results_height(&self) -> usize {
+        (self.cached_height.saturating_sub(self.detail_height()) as usize).saturating_sub(7)
+    }
+
+    /// Rows taken by the detail pane, or 0 when it is off or doesn't fit
+    fn detail_height(&self) -> u16 {
+        let content_height = self.cached_height.saturating_sub(Layout::MARGIN_Y * 2);
+        let table_height = content_height.saturating_sub(Layout::HEADER_HEIGHT + Layout::STATUS_BAR_LINES + Layout::DETAIL_PANE_HEIGHT);
+        if self.show_details && table_height >= Layout::MIN_RESULTS_HEIGHT {
+            Layout::DETAIL_PANE_HEIGHT
+        } else {
+            0
+        }
+    }
+
+    fn draw_detail_pane<W: Write>(&self, out: &mut W, x: u16, width: u16, y: u16) -> Result<(), Box<dyn std::error::Error>> {
+        let right_edge = width - Layout::MARGIN_X - 1;
+        let inner_width = (right_edge as usize).saturating_sub(x as usize + 3);
+        let label_width = 10;
+        let value_width = inner_width.saturating_sub(label_width);
+
+        queue!(
+            out,
+            cursor::MoveTo(x, y),
+            SetForegroundColor(DraculaTheme::PURPLE),
+            Print("┌─ DETAILS "),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print("─".repeat((right_edge as usize).saturating_sub(x as usize + 11))),
+            Print("┐"),
+        )?;
+
+        // Each line is a label plus colored value segments
+        let mut lines: Vec<(&str, Vec<(String, Color)>)> = Vec::new();
+        let result = self.rows().get(self.selected_index).map(|&row| self.row_result(row));
+
+        if let (Some(result), false) = (result, self.sending_to_putio) {
+            for (i, line) in wrap_text(&result.title, value_width, 2).into_iter().enumerate() {
+                lines.push((if i == 0 { "Title" } else { "" }, vec![(line, DraculaTheme::FG)]));
+            }
+
+            let warning = format!("⚠ {}", suspicions(result).join("; "));
+            if warning.len() > "⚠ ".len() {
+                for (i, line) in wrap_text(&warning, value_width, 2).into_iter().enumerate() {
+                    lines.push((if i == 0 { "Warning" } else { "" }, vec![(line, DraculaTheme::ORANGE)]));
+                }
+            }
+
+            lines.push(("Indexer", vec![(result.indexer_names(&self.registry).join(", "), DraculaTheme::CYAN)]));
+            lines.push(("Size", vec![
+                (result.size_str(), DraculaTheme::FG),
+                (format!(" ({} bytes)", result.size), DraculaTheme::FG_DIM),
+            ]));
+            lines.push(("Peers", vec![
+                (format!("{} seeders", result.seeders), DraculaTheme::GREEN),
+                (format!(", {} leechers", result.leechers), DraculaTheme::FG),
+            ]));
+
+            let magnet = result.parsed_magnet();
+            let infohash = magnet.as_ref().and_then(|m| m.infohash()).map(str::to_string);
+            lines.push(("Infohash", vec![(infohash.clone().unwrap_or_else(|| "unknown".to_string()), DraculaTheme::FG)]));
+
+            let badges: Vec<(String, Color)> = release_badges(&result.release())
+                .into_iter()
+                .flat_map(|(badge, color)| [(badge, color), (" ".to_string(), DraculaTheme::FG)])
+                .collect();
+            lines.push(("Release", if badges.is_empty() { vec![("-".to_string(), DraculaTheme::FG_DIM)] } else { badges }));
+
+            let trackers = magnet.map(|m| m.trackers).unwrap_or_default();
+            let tracker_text = format!("{}: {}", trackers.len(), trackers.join(", "));
+            lines.push(("Trackers", vec![(wrap_text(&tracker_text, value_width, 1).concat(), DraculaTheme::FG_DIM)]));
+
+            let sent = infohash.as_deref().and_then(|hash| self.sent_log.get(hash));
+            lines.push(("Sent", match sent {
+                Some(entry) => vec![(format!("✓ {}", format_age(entry.sent_at)), DraculaTheme::GREEN)],
+                None => vec![("no".to_string(), DraculaTheme::FG_DIM)],
+            }));
+        }
+
+        for row in 0..Layout::DETAIL_PANE_HEIGHT - 2 {
+            let row_y = y + 1 + row;
+            queue!(
+                out,
+                cursor::MoveTo(x, row_y),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print("│ "),
+            )?;
+
+            if let Some((label, segments)) = lines.get(row as usize) {
+                queue!(out, SetForegroundColor(DraculaTheme::PURPLE), Print(format!("{:<width$}", label, width = label_width)))?;
+                let mut used = 0;
+                for (text, color) in segments {
+                    let text: String = text.chars().take(value_width.saturating_sub(used)).collect();
+                    used += text.chars().count();
+                    queue!(out, SetForegroundColor(*color), Print(text))?;
+                }
+            } else if row == 0 {
+                queue!(out, SetForegroundColor(DraculaTheme::FG_DIM), Print("Nothing highlighted"))?;
+            }
+
+            queue!(
+                out,
+                cursor::MoveTo(right_edge, row_y),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print("│"),
+            )?;
+        }
+
+        queue!(
+            out,
+            cursor::MoveTo(x, y + Layout::DETAIL_PANE_HEIGHT - 1),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print("└"),
+            Print("─".repeat((right_edge as usize).saturating_sub(x as usize + 1))),
+            Print("┘"),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_status_bars<W: Write>(&self, out: &mut W, x: u16, width: u16, height: u16, y_offset: u16) -> Result<(), Box<dyn std::error::Error>> {
         // Line 1: Navigation help with result count on the right
-        let help_text = "Tab/←→: panels | ↑↓: navigate | Space: toggle | Enter: search/send | ESC: quit";
+        let help_text = if self.picker {
+            "Tab: panels | ↑↓: move | →←: expand | Space: mark | /: filter | i: info | p/s: profile/best | Enter: search/pick | ESC: cancel"
+        } else {
+            "Tab: panels | ↑↓: move | →←: expand | Space: mark | /: filter | i: info | p/s: profile/best | ^S: save | Enter: search/send | F2: settings | ESC: quit"
+        };
         let result_count = if !self.results.is_empty() {
             format!("{} results", self.results.len())
         } else {
@@ -1178,14 +2120,10 @@ impl App {
 
         // Make sure the total width matches exactly
         let total_text_len = help_text.len() + result_count.len();
-        let padding_width = if total_text_len < width as usize {
-            (width as usize) - total_text_len
-        } else {
-            0
-        };
+        let padding_width = (width as usize).saturating_sub(total_text_len);
 
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, height + y_offset - 2),
             SetBackgroundColor(DraculaTheme::BG),
             SetForegroundColor(DraculaTheme::CYAN),
@@ -1199,7 +2137,7 @@ impl App {
         let visible_marquee = self.marquee_cache.render(width as usize);
 
         queue!(
-            stdout,
+            out,
             cursor::MoveTo(x, height + y_offset - 1),
             SetBackgroundColor(DraculaTheme::PINK),
             SetForegroundColor(DraculaTheme::BG),
@@ -1208,7 +2146,7 @@ impl App {
 
         // Reset colors
         queue!(
-            stdout,
+            out,
             SetBackgroundColor(DraculaTheme::BG),
             SetForegroundColor(DraculaTheme::FG),
         )?;
@@ -1216,29 +2154,80 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> Result<bool, Box<dyn std::error::Error>> {
+        if self.prompt.is_some() {
+            self.handle_prompt_key(key)?;
+            return Ok(true);
+        }
+        if self.limit_input.is_some() {
+            self.handle_limit_key(key)?;
+            return Ok(true);
+        }
+        if self.fuzzy_editing {
+            self.handle_fuzzy_key(key)?;
+            return Ok(true);
+        }
+        if self.active_panel == Panel::Search && matches!(self.recall, Some(Recall::Search { .. })) {
+            self.handle_reverse_search_key(key)?;
+            return Ok(true);
+        }
+
         match key.code {
+            // ESC stops a running search before anything else
+            KeyCode::Esc if self.searching || self.revalidating => self.cancel_search(),
+            // A kept `/` filter is cleared before ESC quits
+            KeyCode::Esc if !self.fuzzy_query.is_empty() => {
+                self.fuzzy_query.clear();
+                self.refresh_view();
+            }
             KeyCode::Esc => {
                 return Ok(false); // Quit
             }
+            KeyCode::F(2) if !self.picker => {
+                self.open_settings()?;
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) && !self.picker => {
+                self.start_save_prompt();
+            }
             KeyCode::Tab => {
+                self.recall = None;
                 self.active_panel = match self.active_panel {
                     Panel::Search => Panel::Filters,
-                    Panel::Filters => Panel::Results,
+                    Panel::Filters => Panel::Saved,
+                    Panel::Saved => Panel::Shows,
+                    Panel::Shows => Panel::Results,
                     Panel::Results => Panel::Search,
                 };
                 self.status_message = format!("Switched to {:?} panel", self.active_panel);
             }
             KeyCode::BackTab => {
+                self.recall = None;
                 self.active_panel = match self.active_panel {
                     Panel::Search => Panel::Results,
                     Panel::Filters => Panel::Search,
-                    Panel::Results => Panel::Filters,
+                    Panel::Saved => Panel::Filters,
+                    Panel::Shows => Panel::Saved,
+                    Panel::Results => Panel::Shows,
                 };
                 self.status_message = format!("Switched to {:?} panel", self.active_panel);
             }
             KeyCode::Enter => {
                 match self.active_panel {
-                    Panel::Search | Panel::Filters => self.perform_search()?,
+                    Panel::Filters if self.selected_limit().is_some() => self.handle_filter_key(key)?,
+                    // Enter in the search bar always asks again, even for an unchanged query
+                    Panel::Search => {
+                        self.fetched = None;
+                        self.perform_search()?;
+                    }
+                    Panel::Filters => self.perform_search()?,
+                    Panel::Saved => self.run_saved()?,
+                    Panel::Shows => self.search_next_episode()?,
+                    Panel::Results if self.picker => {
+                        let chosen: Vec<TorrentResult> = self.chosen_results().into_iter().cloned().collect();
+                        if !chosen.is_empty() {
+                            self.picked = Some(chosen);
+                            return Ok(false);
+                        }
+                    }
                     Panel::Results => self.send_to_putio()?,
                 }
             }
@@ -1246,6 +2235,8 @@ impl App {
                 match self.active_panel {
                     Panel::Search => self.handle_search_key(key)?,
                     Panel::Filters => self.handle_filter_key(key)?,
+                    Panel::Saved => self.handle_saved_key(key)?,
+                    Panel::Shows => self.handle_shows_key(key)?,
                     Panel::Results => self.handle_results_key(key)?,
                 }
             }
@@ -1254,14 +2245,69 @@ impl App {
         Ok(true)
     }
 
+    /// Reopen the setup wizard as a settings menu without leaving the app
+    fn open_settings(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let wizard = setup::SetupWizard::settings(self.config.clone());
+
+        if let Some(config) = wizard.run()? {
+            self.config = config;
+            self.chill_client = Self::create_chill_client(&self.config);
+            self.putio_client = Self::create_putio_client(&self.config);
+            self.save_preferences();
+            self.status_message = "✓ Settings saved".to_string();
+
+            if self.debug {
+                eprintln!("[DEBUG] Settings updated from wizard");
+            }
+        }
+
+        Ok(())
+    }
+
     fn handle_search_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
         match key.code {
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.recall = Some(Recall::Search { draft: self.query.clone(), needle: String::new(), index: 0 });
+                self.show_recalled();
+            }
+            KeyCode::Char(_) if key.modifiers.contains(KeyModifiers::CONTROL) => {}
             KeyCode::Char(c) => {
+                self.recall = None;
                 self.query.push(c);
             }
             KeyCode::Backspace => {
+                self.recall = None;
                 self.query.pop();
             }
+            // Up walks back through history, Down walks forward and then
+            // returns to the draft before leaving the search bar
+            KeyCode::Up => {
+                match &mut self.recall {
+                    Some(Recall::Browse { draft, index }) => {
+                        if *index + 1 < self.history.matches(draft).len() {
+                            *index += 1;
+                        }
+                    }
+                    _ => {
+                        if self.history.matches(&self.query).is_empty() {
+                            return Ok(());
+                        }
+                        self.recall = Some(Recall::Browse { draft: self.query.clone(), index: 0 });
+                    }
+                }
+                self.show_recalled();
+            }
+            KeyCode::Down if matches!(self.recall, Some(Recall::Browse { .. })) => {
+                if let Some(Recall::Browse { draft, index }) = &mut self.recall {
+                    if *index == 0 {
+                        self.query = std::mem::take(draft);
+                        self.recall = None;
+                    } else {
+                        *index -= 1;
+                    }
+                }
+                self.show_recalled();
+            }
             KeyCode::Down => {
                 if !self.results.is_empty() {
                     self.active_panel = Panel::Results;
@@ -1274,8 +2320,61 @@ impl App {
         Ok(())
     }
 
+    /// Keys during Ctrl-R reverse search. Enter searches for the match,
+    /// ESC restores the draft and other keys keep the match for editing.
+    fn handle_reverse_search_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(Recall::Search { draft, needle, index }) = &mut self.recall else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if *index + 1 < self.history.matches(needle).len() {
+                    *index += 1;
+                }
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                needle.push(c);
+                *index = 0;
+            }
+            KeyCode::Backspace => {
+                needle.pop();
+                *index = 0;
+            }
+            KeyCode::Esc => {
+                self.query = std::mem::take(draft);
+                self.recall = None;
+                return Ok(());
+            }
+            KeyCode::Enter => {
+                self.show_recalled();
+                return self.perform_search();
+            }
+            _ => {
+                self.show_recalled();
+                self.recall = None;
+                return Ok(());
+            }
+        }
+
+        self.show_recalled();
+        Ok(())
+    }
+
+    /// Put the history entry picked by `recall` into the search bar
+    fn show_recalled(&mut self) {
+        let entry = match &self.recall {
+            Some(Recall::Browse { draft, index }) => self.history.matches(draft).get(*index).map(|e| e.query.clone()),
+            Some(Recall::Search { needle, index, .. }) => self.history.matches(needle).get(*index).map(|e| e.query.clone()),
+            None => None,
+        };
+        if let Some(query) = entry {
+            self.query = query;
+        }
+    }
+
     fn handle_filter_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
-        let total_items = 3 + self.available_indexers.len() + 4 + 2; // 3 sort + indexers + 4 min_seed + 2 nsfw options
+        let total_items = 3 + self.available_indexers.len() + Limit::ALL.len() + 2; // 3 sort + indexers + limits + 2 nsfw options
 
         match key.code {
             KeyCode::Up if self.sort_cursor > 0 => {
@@ -1287,56 +2386,399 @@ impl App {
             KeyCode::Right => {
                 self.active_panel = Panel::Results;
             }
+            // Typing a digit on a limit row starts a fresh value
+            KeyCode::Char(c) if c.is_ascii_digit() && self.selected_limit().is_some() => {
+                self.limit_before = Some(self.filters.clone());
+                self.limit_input = Some(c.to_string());
+                self.preview_limit()?;
+            }
             KeyCode::Char(' ') | KeyCode::Enter => {
                 if self.sort_cursor < 3 {
-                    // Sort mode selection
-                    self.sort_by = match self.sort_cursor {
-                        0 => SortMode::Seeders,
-                        1 => SortMode::Size,
-                        2 => SortMode::Name,
-                        _ => SortMode::Seeders,
-                    };
+                    let sort = &mut self.filters.sort;
+                    match self.sort_cursor {
+                        // Next key, in its natural direction
+                        0 => {
+                            let then_by = sort.then_by.filter(|key| *key != sort.key.next());
+                            *sort = SortOrder { then_by, ..SortOrder::new(sort.key.next()) };
+                        }
+                        1 => sort.descending = !sort.descending,
+                        // Cycle the tie-breaker through the other keys, then off
+                        _ => {
+                            let primary = sort.key;
+                            sort.then_by = SortMode::ALL.iter()
+                                .copied()
+                                .skip_while(|key| sort.then_by.is_some_and(|then_by| *key != then_by))
+                                .skip(usize::from(sort.then_by.is_some()))
+                                .find(|key| *key != primary);
+                        }
+                    }
                 } else if self.sort_cursor < 3 + self.available_indexers.len() {
                     // Indexer selection
                     let idx = self.sort_cursor - 3;
                     if let Some(indexer) = self.available_indexers.get(idx) {
                         if indexer == "all" {
-                            self.selected_indexers = vec!["all".to_string()];
+                            self.filters.indexers = vec!["all".to_string()];
                         } else {
-                            if self.selected_indexers.contains(&"all".to_string()) {
-                                self.selected_indexers.clear();
+                            if self.filters.indexers.contains(&"all".to_string()) {
+                                self.filters.indexers.clear();
                             }
-                            if self.selected_indexers.contains(indexer) {
-                                self.selected_indexers.retain(|x| x != indexer);
-                                if self.selected_indexers.is_empty() {
-                                    self.selected_indexers.push("all".to_string());
+                            if self.filters.indexers.contains(indexer) {
+                                self.filters.indexers.retain(|x| x != indexer);
+                                if self.filters.indexers.is_empty() {
+                                    self.filters.indexers.push("all".to_string());
                                 }
                             } else {
-                                self.selected_indexers.push(indexer.clone());
+                                self.filters.indexers.push(indexer.clone());
                             }
                         }
-                        // Re-run search if we have results to filter
-                        if !self.results.is_empty() && !self.query.is_empty() {
-                            self.perform_search()?;
-                        }
-                    }
-                } else if self.sort_cursor < 3 + self.available_indexers.len() + 4 {
-                    // Min seeds selection
-                    let min_seed_options = vec![0, 5, 10, 100];
-                    let idx = self.sort_cursor - 3 - self.available_indexers.len();
-                    if let Some(&seeds) = min_seed_options.get(idx) {
-                        self.min_seeds = seeds;
                     }
+                } else if self.sort_cursor < 3 + self.available_indexers.len() + Limit::ALL.len() {
+                    // Start editing the limit, prefilled with its current value
+                    let limit = Limit::ALL[self.sort_cursor - 3 - self.available_indexers.len()];
+                    self.limit_before = Some(self.filters.clone());
+                    self.limit_input = Some(self.limit_value(limit).unwrap_or_default());
+                    self.limit_error = false;
+                    return Ok(());
                 } else {
                     // NSFW filter selection
-                    let idx = self.sort_cursor - 3 - self.available_indexers.len() - 4;
-                    self.filter_nsfw = match idx {
+                    let idx = self.sort_cursor - 3 - self.available_indexers.len() - Limit::ALL.len();
+                    self.filters.filter_nsfw = match idx {
                         0 => true,  // Filter NSFW
                         1 => false, // Allow NSFW
                         _ => true,
                     };
                 }
+                self.save_preferences();
+                self.update_results()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Limit row under the Filters cursor, if any
+    fn selected_limit(&self) -> Option<Limit> {
+        self.sort_cursor
+            .checked_sub(3 + self.available_indexers.len())
+            .and_then(|i| Limit::ALL.get(i).copied())
+    }
+
+    /// Current value of a limit as shown and edited, `None` when unset
+    fn limit_value(&self, limit: Limit) -> Option<String> {
+        match limit {
+            Limit::MinSeeds => (self.filters.min_seeds > 0).then(|| self.filters.min_seeds.to_string()),
+            Limit::MaxSeeds => self.filters.max_seeds.map(|n| n.to_string()),
+            Limit::MinSize => self.filters.min_size.map(short_size),
+            Limit::MaxSize => self.filters.max_size.map(short_size),
+        }
+    }
+
+    /// Keys while a limit is being edited: the results follow each valid
+    /// value as it is typed, Enter keeps it, ESC puts the old one back and
+    /// an empty value clears the limit
+    fn handle_limit_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let editing = self.selected_limit().is_some();
+        let Some(input) = self.limit_input.as_mut().filter(|_| editing) else {
+            self.limit_input = None;
+            self.limit_before = None;
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char(c) if (c.is_ascii_alphanumeric() || c == '.') && input.len() < 7 => {
+                input.push(c);
+                self.preview_limit()?;
+            }
+            KeyCode::Backspace => {
+                input.pop();
+                self.preview_limit()?;
+            }
+            KeyCode::Esc => {
+                self.limit_input = None;
+                if let Some(before) = self.limit_before.take() {
+                    self.filters = before;
+                    self.update_results()?;
+                }
+            }
+            KeyCode::Enter if !self.limit_error => {
+                self.limit_input = None;
+                self.limit_before = None;
+                self.save_preferences();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Apply the limit being typed if it parses, keeping the last valid
+    /// value (and flagging the input) while it doesn't
+    fn preview_limit(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let (Some(input), Some(limit)) = (self.limit_input.as_deref(), self.selected_limit()) else {
+            return Ok(());
+        };
+        let input = input.trim();
+        let seeds = || if input.is_empty() { Some(None) } else { input.parse::<u32>().ok().map(Some) };
+        let size = || if input.is_empty() { Some(None) } else { parse_size(input).map(Some) };
+
+        let applied = match limit {
+            Limit::MinSeeds => seeds().map(|n| self.filters.min_seeds = n.unwrap_or(0)),
+            Limit::MaxSeeds => seeds().map(|n| self.filters.max_seeds = n),
+            Limit::MinSize => size().map(|n| self.filters.min_size = n),
+            Limit::MaxSize => size().map(|n| self.filters.max_size = n),
+        };
+
+        self.limit_error = applied.is_none();
+        if applied.is_some() {
+            self.update_results()?;
+        }
+        Ok(())
+    }
+
+    /// Ask for a name to save the search box query under, or the active
+    /// query once the box has been cleared
+    fn start_save_prompt(&mut self) {
+        let query = if self.query.trim().is_empty() { &self.active_query } else { &self.query };
+        if query.trim().is_empty() {
+            self.status_message = "✗ Nothing to save".to_string();
+            return;
+        }
+
+        let name = ParsedQuery::parse(query).keyword;
+        self.prompt = Some(Prompt::SaveSearch { query: query.trim().to_string(), name });
+    }
+
+    /// Keys while a prompt is open: Enter confirms, ESC cancels
+    fn handle_prompt_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(prompt) = self.prompt.as_mut() else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => prompt.text_mut().push(c),
+            KeyCode::Backspace => {
+                prompt.text_mut().pop();
+            }
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Enter if !prompt.text().trim().is_empty() => match self.prompt.take() {
+                Some(Prompt::SaveSearch { query, name }) => self.save_search(&name, &query),
+                Some(Prompt::TrackShow(input)) => self.track_show(&input),
+                None => {}
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn save_search(&mut self, name: &str, query: &str) {
+        self.saved.add(name, query, &self.filters);
+        if let Err(e) = self.saved.save() {
+            if self.debug {
+                eprintln!("[DEBUG] Failed to save searches: {}", e);
+            }
+        }
+        self.saved_cursor = self.saved.list().iter()
+            .position(|s| s.name == name.trim())
+            .unwrap_or(0);
+        self.status_message = format!("✓ Saved '{}'", name.trim());
+    }
+
+    fn track_show(&mut self, input: &str) {
+        if let Some(name) = self.series.add(input) {
+            self.save_series();
+            self.series_cursor = self.series.list().iter().position(|show| show.name == name).unwrap_or(0);
+            self.status_message = format!("✓ Tracking '{}'", name);
+        }
+    }
+
+    fn save_series(&self) {
+        if let Err(e) = self.series.save() {
+            if self.debug {
+                eprintln!("[DEBUG] Failed to save tracked shows: {}", e);
+            }
+        }
+    }
+
+    fn handle_shows_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let selected = self.series.list().get(self.series_cursor).map(|show| show.name.clone());
+
+        match key.code {
+            KeyCode::Up if self.series_cursor > 0 => self.series_cursor -= 1,
+            KeyCode::Down if self.series_cursor + 1 < self.series.list().len() => self.series_cursor += 1,
+            KeyCode::Right => self.active_panel = Panel::Results,
+            KeyCode::Left => self.active_panel = Panel::Saved,
+            KeyCode::Char('a') => self.prompt = Some(Prompt::TrackShow(String::new())),
+            KeyCode::Char('g') if !self.picker => self.grab_next_episode(),
+            // Correct the next expected episode by hand
+            KeyCode::Char(c @ ('+' | '-')) => {
+                if let Some(name) = selected {
+                    self.series.step(&name, c == '+');
+                    self.series_note = None;
+                    self.save_series();
+                }
+            }
+            KeyCode::Delete | KeyCode::Char('d') => {
+                if let Some(name) = selected {
+                    self.series.remove(&name);
+                    self.save_series();
+                    self.series_cursor = self.series_cursor.min(self.series.list().len().saturating_sub(1));
+                    self.status_message = format!("✓ Stopped tracking '{}'", name);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Search for the highlighted show's next episode in the results table
+    fn search_next_episode(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(show) = self.series.list().get(self.series_cursor) else {
+            return Ok(());
+        };
+        self.query = format!("{} {}", show.name, show.next_label());
+        self.perform_search()
+    }
+
+    /// Look for the highlighted show's next episode in the background and
+    /// send the best exact match (or a pack of its season) to Put.io
+    fn grab_next_episode(&mut self) {
+        let Some(show) = self.series.list().get(self.series_cursor).cloned() else { return };
+        if self.grabbing.is_some() {
+            return;
+        }
+        let Some(client) = self.chill_client.clone() else {
+            self.series_note = Some((show.name, "✗ Chill API key not configured".to_string()));
+            return;
+        };
+        if self.config.cache.offline {
+            self.series_note = Some((show.name, "✗ Offline".to_string()));
+            return;
+        }
+
+        // The panel's seed limit, NSFW switch and the blocklist still apply
+        let mut filters = Filters {
+            sort: self.filters.sort,
+            min_seeds: self.filters.min_seeds,
+            filter_nsfw: self.filters.filter_nsfw,
+            registry: Arc::clone(&self.registry),
+            ..Filters::default()
+        };
+        self.config.blocklist.apply_to(&mut filters);
+
+        self.grabbing = Some(show.name.clone());
+        self.series_note = None;
+        self.should_animate = true;
+        let slot = Arc::clone(&self.grab_result);
+        let debug = self.debug;
+
+        thread::spawn(move || {
+            let mut found = Ok(None);
+            for (query, season, episode) in show.search_plan() {
+                if debug {
+                    eprintln!("[DEBUG] Looking for S{:02}E{:02} with '{}'", season, episode, query);
+                }
+                match client.search(&query, None, filters.filter_nsfw) {
+                    Ok(results) => {
+                        let mut results = merge_duplicates(results);
+                        filters.apply(&mut results);
+                        if let Some(result) = find_episode(&results, &show.name, season, episode) {
+                            found = Ok(Some(result.clone()));
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        found = Err(e.to_string());
+                        break;
+                    }
+                }
+            }
+
+            if let Ok(mut guard) = slot.lock() {
+                *guard = Some(found);
+            }
+        });
+    }
+
+    fn handle_saved_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match key.code {
+            KeyCode::Up if self.saved_cursor > 0 => self.saved_cursor -= 1,
+            KeyCode::Down if self.saved_cursor + 1 < self.saved.list().len() => self.saved_cursor += 1,
+            KeyCode::Right => self.active_panel = Panel::Results,
+            KeyCode::Left => self.active_panel = Panel::Filters,
+            KeyCode::Delete | KeyCode::Char('d') => {
+                let Some(name) = self.saved.list().get(self.saved_cursor).map(|s| s.name.clone()) else {
+                    return Ok(());
+                };
+                if self.saved.remove(&name) {
+                    if let Err(e) = self.saved.save() {
+                        if self.debug {
+                            eprintln!("[DEBUG] Failed to save searches: {}", e);
+                        }
+                    }
+                    self.saved_cursor = self.saved_cursor.min(self.saved.list().len().saturating_sub(1));
+                    self.status_message = format!("✓ Removed '{}'", name);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Run the highlighted saved search with its own filters. It always
+    /// goes to the API so the run can be compared with the previous one.
+    fn run_saved(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(search) = self.saved.list().get(self.saved_cursor).cloned() else {
+            return Ok(());
+        };
+
+        self.query = search.query;
+        search.filters.restore(&mut self.filters);
+        self.fetched = None;
+        self.skip_cache = true;
+        self.perform_search()?;
+        if self.searching {
+            self.running_saved = Some(search.name);
+        }
+        Ok(())
+    }
+
+    /// Compare a finished saved search run with the previous one and
+    /// badge what it hadn't seen
+    fn record_saved_run(&mut self) {
+        let Some(name) = self.running_saved.take() else { return };
+        let Some(search) = self.saved.get_mut(&name) else { return };
+
+        search.record_run(&self.results);
+        self.new_hashes = search.new.iter().cloned().collect();
+        self.status_message = format!("✓ {} new since the last run", search.new.len());
+
+        if let Err(e) = self.saved.save() {
+            if self.debug {
+                eprintln!("[DEBUG] Failed to save searches: {}", e);
             }
+        }
+    }
+
+    /// Keys while typing the `/` filter: the results narrow on every
+    /// keystroke, Enter keeps the filter and ESC clears it
+    fn handle_fuzzy_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.fuzzy_query.push(c);
+                self.refresh_view();
+            }
+            KeyCode::Backspace => {
+                self.fuzzy_query.pop();
+                self.refresh_view();
+            }
+            KeyCode::Enter => self.fuzzy_editing = false,
+            KeyCode::Esc => {
+                self.fuzzy_editing = false;
+                self.fuzzy_query.clear();
+                self.refresh_view();
+            }
+            // Stay in the results while typing rather than moving up to the search box
+            KeyCode::Up if self.selected_index > 0 => self.handle_results_key(key)?,
+            KeyCode::Down => self.handle_results_key(key)?,
             _ => {}
         }
         Ok(())
@@ -1361,9 +2803,9 @@ impl App {
                     self.active_panel = Panel::Search;
                 }
             }
-            KeyCode::Down if !self.results.is_empty() && self.selected_index < self.results.len().saturating_sub(1) => {
+            KeyCode::Down if !self.results.is_empty() && self.selected_index < self.rows().len().saturating_sub(1) => {
                 self.selected_index += 1;
-                let results_height = (self.cached_height as usize).saturating_sub(7);
+                let results_height = self.results_height();
                 if self.selected_index >= self.scroll_offset + results_height {
                     self.scroll_offset = self.selected_index - results_height + 1;
                 }
@@ -1371,11 +2813,66 @@ impl App {
                 self.title_scroll_offset = 0;
                 self.title_scroll_direction = 1;
             }
+            // Right expands a merged result, Left collapses it before leaving the panel
+            KeyCode::Right => {
+                if let Some(Row::Result(index)) = self.rows().get(self.selected_index) {
+                    if !self.results[*index].sources.is_empty() {
+                        self.expanded.insert(*index);
+                    }
+                }
+            }
             KeyCode::Left => {
-                self.active_panel = Panel::Filters;
+                match self.rows().get(self.selected_index) {
+                    Some(&Row::Result(index)) if self.expanded.contains(&index) => {
+                        self.expanded.remove(&index);
+                    }
+                    Some(&Row::Source(index, _)) => {
+                        self.expanded.remove(&index);
+                        self.selected_index = self.rows().iter().position(|r| *r == Row::Result(index)).unwrap_or(0);
+                        self.scroll_offset = self.scroll_offset.min(self.selected_index);
+                    }
+                    _ => self.active_panel = Panel::Filters,
+                }
+            }
+            // Hide the highlighted result's release group (b) or the torrent itself (B) for good
+            KeyCode::Char(c @ ('b' | 'B')) => {
+                if let Some(&row) = self.rows().get(self.selected_index) {
+                    let result = self.row_result(row).clone();
+                    let blocked = if c == 'b' {
+                        self.config.blocklist.block_group(&result)
+                    } else {
+                        self.config.blocklist.block_infohash(&result)
+                    };
+
+                    if let Some(blocked) = blocked {
+                        if let Err(e) = self.config.save() {
+                            if self.debug {
+                                eprintln!("[DEBUG] Failed to save blocklist: {}", e);
+                            }
+                        }
+                        self.refresh_view();
+                        self.status_message = format!("✓ Blocked {}", blocked);
+                    }
+                }
+            }
+            KeyCode::Char('/') if !self.raw_results.is_empty() => {
+                self.fuzzy_editing = true;
+            }
+            KeyCode::Char('i') => {
+                self.show_details = !self.show_details;
+                self.save_preferences();
             }
+            // Hide or show results that look like fakes or malware
+            KeyCode::Char('!') => {
+                self.filters.hide_suspicious = !self.filters.hide_suspicious;
+                self.save_preferences();
+                self.refresh_view();
+            }
+            KeyCode::Char('p') => self.cycle_profile(),
+            KeyCode::Char('s') => self.select_best(),
             KeyCode::Char(' ') if !self.results.is_empty() => {
-                if let Some(result) = self.results.get_mut(self.selected_index) {
+                if let Some(&row) = self.rows().get(self.selected_index) {
+                    let result = self.row_result_mut(row);
                     result.selected = !result.selected;
                 }
             }
@@ -1384,116 +2881,515 @@ impl App {
         Ok(())
     }
 
+    /// The quality profile results are scored against, if any
+    fn profile(&self) -> Option<&QualityProfile> {
+        let name = self.config.ui.profile.as_ref()?;
+        self.config.profiles.iter().find(|profile| profile.name == *name)
+    }
+
+    /// Switch to the next quality profile in the config, then back to none
+    fn cycle_profile(&mut self) {
+        let profiles = &self.config.profiles;
+        let next = match self.config.ui.profile.as_ref().and_then(|name| profiles.iter().position(|p| p.name == *name)) {
+            Some(i) => profiles.get(i + 1),
+            None => profiles.first(),
+        };
+        self.config.ui.profile = next.map(|profile| profile.name.clone());
+        self.save_preferences();
+    }
+
+    /// Mark and highlight the result the active profile scores highest;
+    /// the first one wins ties, so the sort order breaks them
+    fn select_best(&mut self) {
+        let Some(profile) = self.profile() else {
+            self.status_message = "No quality profile active (p to pick one)".to_string();
+            return;
+        };
+
+        let mut best: Option<(u32, usize)> = None;
+        for (index, result) in self.results.iter().enumerate() {
+            if let Some(score) = profile.score(result) {
+                if best.is_none_or(|(best_score, _)| score > best_score) {
+                    best = Some((score, index));
+                }
+            }
+        }
+        let Some((_, index)) = best else {
+            self.status_message = format!("Nothing passes the {} profile", profile.name);
+            return;
+        };
+
+        self.results[index].selected = true;
+        self.selected_index = self.rows().iter().position(|row| *row == Row::Result(index)).unwrap_or(0);
+        let results_height = self.results_height();
+        if self.selected_index < self.scroll_offset {
+            self.scroll_offset = self.selected_index;
+        } else if self.selected_index >= self.scroll_offset + results_height {
+            self.scroll_offset = self.selected_index + 1 - results_height;
+        }
+        self.title_scroll_offset = 0;
+        self.title_scroll_direction = 1;
+    }
+
+    /// Search for what's in the search box
     fn perform_search(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if self.query.is_empty() {
             return Ok(());
         }
 
-        // Clear results and show spinner
-        self.results.clear();
-        self.searching = true;
-        self.spinner_frame = 0;
-        self.should_animate = true;
-        self.status_message = "Fetching results...".to_string();
+        // Taken before the query changes, so a cancel can bring it back
+        if self.before_search.is_none() {
+            self.before_search = Some(self.shown_results());
+        }
 
-        if let Some(ref client) = self.chill_client {
-            // Clone data needed for background thread
-            let client = client.clone();
-            let query = self.query.clone();
-            let min_seeds = self.min_seeds;
-            let sort_by = self.sort_by;
-            let filter_nsfw = self.filter_nsfw;
-            let debug = self.debug;
+        self.recall = None;
+        self.running_saved = None;
+        self.new_hashes.clear();
+        self.active_query = self.query.clone();
+        if !self.picker {
+            self.history.record(&self.active_query, &self.filters);
+        }
 
-            // Map display names to API names
-            let map_indexer = |name: &str| -> String {
-                match name {
-                    "TPB" => "thepiratebay".to_string(),
-                    "EZTV" => "eztv".to_string(),
-                    "RUtracker" => "rutracker".to_string(),
-                    "RARBG" => "therarbg".to_string(),
-                    "YTS" => "yts".to_string(),
-                    _ => name.to_string(),
-                }
-            };
+        self.update_results()?;
+        if !self.searching && !self.revalidating {
+            self.before_search = None;
+        }
+        if !self.searching && self.fetched.is_some() {
+            self.active_panel = Panel::Results;
+            self.record_result_count();
+        }
+        Ok(())
+    }
+
+    /// Store how many results the active query shows and save the history
+    fn record_result_count(&mut self) {
+        if self.picker {
+            return;
+        }
+
+        self.history.set_result_count(&self.active_query, self.results.len());
+        if let Err(e) = self.history.save() {
+            if self.debug {
+                eprintln!("[DEBUG] Failed to save history: {}", e);
+            }
+        }
+    }
+
+    /// Effective filters and API request for the active query. Inline
+    /// filters (seeds:>50, idx:yts, -cam, ...) override the panel; only the
+    /// keyword is sent to the API.
+    fn active_search(&self) -> Option<(Filters, FetchKey)> {
+        let parsed = ParsedQuery::parse(&self.active_query);
+        if parsed.keyword.is_empty() {
+            return None;
+        }
+        let query_indexers = parsed.indexers.as_ref().map(|names| self.resolve_indexers(names));
+        let mut filters = parsed.apply_to(&self.filters, query_indexers);
+        self.config.blocklist.apply_to(&mut filters);
 
-            let all_indexers: Vec<String> = self.available_indexers.iter()
+        // Map display names to API names
+        let mut indexers: Vec<String> = if filters.indexers.contains(&"all".to_string()) {
+            self.available_indexers.iter()
                 .filter(|i| *i != "all")
-                .map(|i| map_indexer(i))
-                .collect();
+                .map(|i| self.registry.api_id(i).to_string())
+                .collect()
+        } else {
+            filters.indexers.iter().map(|i| self.registry.api_id(i).to_string()).collect()
+        };
+        indexers.sort();
 
-            let selected_mapped: Vec<String> = self.selected_indexers.iter()
-                .map(|i| map_indexer(i))
-                .collect();
+        let key = FetchKey { keyword: parsed.keyword, indexers, filter_nsfw: filters.filter_nsfw };
+        Some((filters, key))
+    }
 
-            let indexers = if self.selected_indexers.contains(&"all".to_string()) {
-                all_indexers
-            } else {
-                selected_mapped
-            };
+    /// Bring the results in line with the active query and filters,
+    /// re-filtering the last fetch locally when it already covers them
+    fn update_results(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_query.is_empty() {
+            return Ok(());
+        }
+
+        let Some((filters, key)) = self.active_search() else {
+            self.status_message = "✗ Nothing to search for".to_string();
+            return Ok(());
+        };
+
+        // Rows still streaming in for a covering fetch are filtered as they land
+        match self.fetched.as_ref().or(self.pending_fetch.as_ref()) {
+            Some(fetched) if fetched.covers(&key) => self.refresh_view(),
+            _ => self.fetch(filters, key),
+        }
+        Ok(())
+    }
+
+    /// Recompute the visible results from `raw_results`, keeping marks and
+    /// the highlighted result where possible
+    fn refresh_view(&mut self) {
+        let Some((mut filters, key)) = self.active_search() else { return };
+
+        // The API already limited the fetch to exactly these indexers
+        if self.fetched.as_ref().or(self.pending_fetch.as_ref()).is_some_and(|fetched| fetched.indexers == key.indexers) {
+            filters.indexers = vec!["all".to_string()];
+        }
+
+        let marked: HashSet<String> = self.results.iter()
+            .flat_map(|r| std::iter::once(r).chain(&r.sources))
+            .filter(|r| r.selected)
+            .map(|r| r.magnet.clone())
+            .collect();
+        let highlighted = match self.rows().get(self.selected_index) {
+            Some(&Row::Result(index)) | Some(&Row::Source(index, _)) => Some(self.results[index].magnet.clone()),
+            None => None,
+        };
+
+        let mut results = self.raw_results.clone();
+        filters.apply(&mut results);
+        if !self.fuzzy_query.is_empty() {
+            results.retain(|r| fuzzy_match(&self.fuzzy_query, &self.fuzzy_text(r)).is_some());
+        }
+        for result in &mut results {
+            result.selected = marked.contains(&result.magnet);
+            for source in &mut result.sources {
+                source.selected = marked.contains(&source.magnet);
+            }
+        }
+
+        self.selected_index = highlighted
+            .and_then(|magnet| results.iter().position(|r| r.magnet == magnet))
+            .unwrap_or(0);
+        self.results = results;
+        self.expanded.clear();
+
+        let results_height = self.results_height();
+        self.scroll_offset = self.scroll_offset.min(self.selected_index);
+        if self.selected_index >= self.scroll_offset + results_height {
+            self.scroll_offset = self.selected_index + 1 - results_height;
+        }
+        self.status_message = format!("✓ {} of {} results", self.results.len(), self.raw_results.len());
+    }
+
+    /// What the `/` filter matches against: the title, then the indexers
+    fn fuzzy_text(&self, result: &TorrentResult) -> String {
+        format!("{} {}", result.title, result.indexer_names(&self.registry).join(" "))
+    }
+
+    /// Fetch `key` from the API in the background, one request per indexer
+    /// so results show up as each one answers. A cached response is shown
+    /// right away; it is only refetched once older than the TTL, and never
+    /// offline.
+    fn fetch(&mut self, filters: Filters, key: FetchKey) {
+        if self.before_search.is_none() {
+            self.before_search = Some(self.shown_results());
+        }
+        self.search_generation += 1;
+        self.fuzzy_query.clear();
+        self.fuzzy_editing = false;
+        self.expanded.clear();
+        self.revalidating = false;
+        let skip_cache = std::mem::take(&mut self.skip_cache) && !self.config.cache.offline;
+
+        let cached = if skip_cache { None } else { SearchCache::get(&key.keyword, &key.indexers, key.filter_nsfw) };
+        match cached {
+            Some(cached) => {
+                let fresh = self.config.cache.is_fresh(cached.age());
+                self.raw_results = merge_duplicates(cached.results);
+                self.fetched = Some(key.clone());
+                self.cached_at = Some(cached.fetched_at);
+                self.results.clear();
+                self.selected_index = 0;
+                self.scroll_offset = 0;
+                self.refresh_view();
+                self.status_message = format!("✓ {} cached results", self.results.len());
+                if fresh {
+                    self.before_search = None;
+                    return;
+                }
+                self.revalidating = true;
+            }
+            None if self.config.cache.offline => {
+                self.raw_results.clear();
+                self.results.clear();
+                self.fetched = None;
+                self.cached_at = None;
+                self.status_message = format!("✗ '{}' is not in the cache (offline)", key.keyword);
+                self.before_search = None;
+                return;
+            }
+            None => {
+                // Clear results and show spinner until the first rows arrive
+                self.raw_results.clear();
+                self.results.clear();
+                self.fetched = None;
+                self.cached_at = None;
+                self.selected_index = 0;
+                self.scroll_offset = 0;
+                self.searching = true;
+                self.spinner_frame = 0;
+                self.should_animate = true;
+                self.status_message = "Fetching results...".to_string();
+            }
+        }
+
+        let Some(client) = self.chill_client.clone() else {
+            self.status_message = "✗ Chill API key not configured".to_string();
+            self.searching = false;
+            self.revalidating = false;
+            self.before_search = None;
+            return;
+        };
+
+        // "all" (or no indexer at all) is one request per enabled indexer
+        let indexers: Vec<String> = if key.indexers.is_empty() || key.indexers.iter().any(|i| i == "all") {
+            self.registry.enabled().map(|indexer| indexer.id.clone()).collect()
+        } else {
+            key.indexers.clone()
+        };
+        if self.debug {
+            eprintln!("[DEBUG] Starting background search for: {} on {:?} ({:?})", key.keyword, indexers, filters);
+        }
+
+        self.streamed.clear();
+        self.indexer_status = indexers.iter().map(|i| (i.clone(), IndexerStatus::Pending)).collect();
+        if let Ok(mut replies) = self.search_results.lock() {
+            replies.clear();
+        }
 
-            let results_arc = Arc::clone(&self.search_results);
+        for indexer in indexers {
+            let client = client.clone();
+            let slot = Arc::clone(&self.search_results);
+            let keyword = key.keyword.clone();
+            let filter_nsfw = key.filter_nsfw;
+            let generation = self.search_generation;
 
-            // Spawn background thread for search
             thread::spawn(move || {
-                if debug {
-                    eprintln!("[DEBUG] Starting background search for: {}", query);
+                let started = Instant::now();
+                let result = client.search(&keyword, Some(std::slice::from_ref(&indexer)), filter_nsfw)
+                    .map_err(|e| e.to_string());
+                let reply = IndexerReply { generation, indexer, result, elapsed: started.elapsed() };
+
+                if let Ok(mut replies) = slot.lock() {
+                    replies.push(reply);
                 }
+            });
+        }
+        self.pending_fetch = Some(key);
+    }
 
-                let search_result = client.search(&query, Some(&indexers), filter_nsfw);
+    /// Take one indexer's answer: merge its rows into the table (keeping the
+    /// sort, marks and highlighted row), and wrap up once none is pending.
+    /// While revalidating, the cached rows stay until every indexer answered.
+    fn receive_reply(&mut self, reply: IndexerReply) {
+        // Late answers to a superseded or cancelled search
+        if reply.generation != self.search_generation {
+            if self.debug {
+                eprintln!("[DEBUG] Dropped a stale answer from {}", reply.indexer);
+            }
+            return;
+        }
 
-                let processed_result = search_result.map(|mut results| {
-                    // Filter by min seeds
-                    if min_seeds > 0 {
-                        results.retain(|r| r.seeders >= min_seeds);
+        match reply.result {
+            Ok(results) => {
+                if self.debug {
+                    eprintln!("[DEBUG] {} answered with {} results in {:?}", reply.indexer, results.len(), reply.elapsed);
+                }
+                self.indexer_status.insert(reply.indexer, IndexerStatus::Done { count: results.len(), elapsed: reply.elapsed });
+                self.streamed.extend(results);
+
+                if !self.revalidating {
+                    let first_rows = self.results.is_empty();
+                    self.raw_results = merge_duplicates(self.streamed.clone());
+                    self.refresh_view();
+                    if first_rows && !self.results.is_empty() {
+                        self.active_panel = Panel::Results;
                     }
+                }
+            }
+            Err(e) => {
+                if self.debug {
+                    eprintln!("[DEBUG] {} failed after {:?}: {}", reply.indexer, reply.elapsed, e);
+                }
+                self.indexer_status.insert(reply.indexer, IndexerStatus::Failed(e));
+            }
+        }
 
-                    // Sort results
-                    match sort_by {
-                        SortMode::Seeders => results.sort_by(|a, b| b.seeders.cmp(&a.seeders)),
-                        SortMode::Name => results.sort_by(|a, b| a.title.cmp(&b.title)),
-                        SortMode::Size => results.sort_by(|a, b| b.size.cmp(&a.size)),
-                    }
+        if !self.indexer_status.values().any(|status| matches!(status, IndexerStatus::Pending)) {
+            self.finish_search();
+        }
+    }
 
-                    results
-                }).map_err(|e| e.to_string());
+    /// Every indexer has answered: settle the results and cache them
+    fn finish_search(&mut self) {
+        let Some(mut key) = self.pending_fetch.take() else { return };
+        let failed: Vec<(&String, &String)> = self.indexer_status.iter()
+            .filter_map(|(indexer, status)| match status {
+                IndexerStatus::Failed(e) => Some((indexer, e)),
+                _ => None,
+            })
+            .collect();
+        let answered = failed.len() < self.indexer_status.len();
+        let complete = failed.is_empty();
+        let failures = match failed.first() {
+            Some((indexer, e)) if failed.len() == 1 => format!("{} failed: {}", self.registry.display_name(indexer), e),
+            Some(_) => format!("{} indexers failed", failed.len()),
+            None => String::new(),
+        };
 
-                // Store result in shared state
-                if let Ok(mut guard) = results_arc.lock() {
-                    *guard = Some(processed_result);
+        // Only complete answers are cached, so a failed indexer isn't later
+        // served as having found nothing
+        if failed.is_empty() {
+            if let Err(e) = SearchCache::put(&key.keyword, &key.indexers, key.filter_nsfw, &self.streamed) {
+                if self.debug {
+                    eprintln!("[DEBUG] Failed to cache results: {}", e);
                 }
+            }
+        } else {
+            // Leave the failed indexers out, so searching again retries them
+            let failed: HashSet<String> = failed.iter().map(|(indexer, _)| (*indexer).clone()).collect();
+            key.indexers.retain(|indexer| !failed.contains(indexer));
+        }
 
-                if debug {
-                    eprintln!("[DEBUG] Background search completed");
-                }
-            });
+        if !answered {
+            // Keep cached rows if a refresh fails; otherwise there is nothing
+            if !self.revalidating {
+                self.raw_results.clear();
+                self.fetched = None;
+                self.running_saved = None;
+            }
+            self.status_message = format!("✗ Search error: {}", failures);
         } else {
-            self.status_message = "✗ Chill API key not configured".to_string();
-            self.searching = false;
+            if self.revalidating {
+                self.raw_results = merge_duplicates(std::mem::take(&mut self.streamed));
+            } else {
+                self.active_panel = Panel::Results;
+            }
+            self.fetched = Some(key);
+            self.cached_at = None;
+            self.refresh_view();
+            self.status_message = if failures.is_empty() {
+                format!("✓ Found {} results", self.results.len())
+            } else {
+                format!("✓ Found {} results ({})", self.results.len(), failures)
+            };
+            // A partial answer would undercount, and the failed indexers'
+            // rows would show up as new on the next run
+            if complete {
+                self.record_result_count();
+                self.record_saved_run();
+            } else {
+                self.running_saved = None;
+            }
+            self.should_animate = true;
         }
 
-        Ok(())
+        self.streamed.clear();
+        self.searching = false;
+        self.revalidating = false;
+        self.before_search = None;
     }
 
-    fn send_to_putio(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Stop the running search: answers still on their way are dropped and
+    /// the results from before it come back. Stopping a background refresh
+    /// keeps the cached results it was refreshing.
+    fn cancel_search(&mut self) {
+        self.search_generation += 1;
+        self.pending_fetch = None;
+        self.indexer_status.clear();
+        self.streamed.clear();
+        self.running_saved = None;
+        self.searching = false;
+        let previous = self.before_search.take();
+
+        if std::mem::take(&mut self.revalidating) {
+            self.status_message = "Refresh cancelled, showing cached results".to_string();
+            return;
+        }
+        if let Some(shown) = previous {
+            self.active_query = shown.active_query;
+            self.raw_results = shown.raw_results;
+            self.results = shown.results;
+            self.fetched = shown.fetched;
+            self.cached_at = shown.cached_at;
+            self.new_hashes = shown.new_hashes;
+            self.expanded = shown.expanded;
+            self.fuzzy_query = shown.fuzzy_query;
+            self.selected_index = shown.selected_index;
+            self.scroll_offset = shown.scroll_offset;
+        }
+        self.status_message = "Search cancelled".to_string();
+    }
+
+    /// Snapshot of the table, for `cancel_search`
+    fn shown_results(&self) -> ShownResults {
+        ShownResults {
+            active_query: self.active_query.clone(),
+            raw_results: self.raw_results.clone(),
+            results: self.results.clone(),
+            fetched: self.fetched.clone(),
+            cached_at: self.cached_at,
+            new_hashes: self.new_hashes.clone(),
+            expanded: self.expanded.clone(),
+            fuzzy_query: self.fuzzy_query.clone(),
+            selected_index: self.selected_index,
+            scroll_offset: self.scroll_offset,
+        }
+    }
+
+    /// Table rows in display order, with expanded groups followed by their copies
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::with_capacity(self.results.len());
+        for (index, result) in self.results.iter().enumerate() {
+            rows.push(Row::Result(index));
+            if self.expanded.contains(&index) {
+                rows.extend((0..result.sources.len()).map(|source| Row::Source(index, source)));
+            }
+        }
+        rows
+    }
+
+    fn row_result(&self, row: Row) -> &TorrentResult {
+        match row {
+            Row::Result(index) => &self.results[index],
+            Row::Source(index, source) => &self.results[index].sources[source],
+        }
+    }
+
+    fn row_result_mut(&mut self, row: Row) -> &mut TorrentResult {
+        match row {
+            Row::Result(index) => &mut self.results[index],
+            Row::Source(index, source) => &mut self.results[index].sources[source],
+        }
+    }
+
+    /// Marked results (including marked per-indexer copies), or the
+    /// highlighted one if nothing is marked
+    fn chosen_results(&self) -> Vec<&TorrentResult> {
         let selected_results: Vec<_> = self
             .results
             .iter()
+            .flat_map(|r| std::iter::once(r).chain(&r.sources))
             .filter(|r| r.selected)
             .collect();
 
         // If nothing is explicitly selected, use the currently highlighted item
-        let items_to_send: Vec<_> = if selected_results.is_empty() {
-            if let Some(current) = self.results.get(self.selected_index) {
-                vec![current]
-            } else {
-                self.status_message = "✗ No results available".to_string();
-                return Ok(());
-            }
+        if selected_results.is_empty() {
+            self.rows().get(self.selected_index).map(|&row| self.row_result(row)).into_iter().collect()
         } else {
             selected_results
-        };
+        }
+    }
+
+    fn send_to_putio(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let items_to_send: Vec<TorrentResult> = self.chosen_results().into_iter().cloned().collect();
+        if items_to_send.is_empty() {
+            self.status_message = "✗ No results available".to_string();
+            return Ok(());
+        }
 
+        self.send_results(items_to_send);
+        Ok(())
+    }
+
+    fn send_results(&mut self, items_to_send: Vec<TorrentResult>) {
         // Store info for display message
         let file_count = items_to_send.len();
         let first_title = items_to_send.first().map(|r| r.title.clone()).unwrap_or_default();
@@ -1501,6 +3397,7 @@ impl App {
         // Show sending message with spinner
         self.sending_to_putio = true;
         self.sending_complete = false;
+        self.send_failed = false;
         self.should_animate = true;
         self.sent_file_name = if file_count == 1 {
             format!("Sending '{}' to Put.io", first_title)
@@ -1518,10 +3415,15 @@ impl App {
             let debug = self.debug;
             let magnets: Vec<String> = items_to_send.iter().map(|r| r.magnet.clone()).collect();
             let send_complete = Arc::clone(&self.send_complete);
+            let send_result = Arc::clone(&self.send_result);
+            self.sending = items_to_send;
 
             // Clear selections immediately
             for result in &mut self.results {
                 result.selected = false;
+                for source in &mut result.sources {
+                    source.selected = false;
+                }
             }
 
             // Spawn background thread
@@ -1531,31 +3433,40 @@ impl App {
                 }
 
                 // Ensure folder exists
-                let folder_id = match folder_id {
-                    Some(id) => id,
-                    None => {
-                        match client.find_or_create_folder(&folder_name) {
-                            Ok(id) => id,
-                            Err(e) => {
-                                if debug {
-                                    eprintln!("[DEBUG] Failed to create folder: {}", e);
-                                }
-                                return;
-                            }
+                let folder = match folder_id {
+                    Some(id) => Ok(id),
+                    None => client.find_or_create_folder_path(&folder_name).map_err(|e| {
+                        if debug {
+                            eprintln!("[DEBUG] Failed to create folder: {}", e);
                         }
-                    }
+                        format!("folder: {}", e)
+                    }),
                 };
 
                 // Send transfers
-                for magnet in magnets {
-                    if let Err(e) = client.add_transfer(&magnet, folder_id) {
-                        if debug {
-                            eprintln!("[DEBUG] Failed to add transfer: {}", e);
-                        }
-                    }
-                }
+                let outcome = folder.map(|folder_id| {
+                    magnets.iter()
+                        .map(|magnet| match client.add_transfer(magnet, folder_id) {
+                            Ok(transfer) => {
+                                if debug {
+                                    eprintln!("[DEBUG] Added transfer {}: {}", transfer.id, transfer.name);
+                                }
+                                Ok(())
+                            }
+                            Err(e) => {
+                                if debug {
+                                    eprintln!("[DEBUG] Failed to add transfer: {}", e);
+                                }
+                                Err(e.to_string())
+                            }
+                        })
+                        .collect()
+                });
 
                 // Signal completion
+                if let Ok(mut slot) = send_result.lock() {
+                    *slot = Some(outcome);
+                }
                 if let Ok(mut guard) = send_complete.lock() {
                     *guard = true;
                 }
@@ -1567,7 +3478,52 @@ impl App {
         } else {
             self.status_message = "✗ Put.io not configured".to_string();
         }
+    }
 
-        Ok(())
+    /// Record the results Put.io accepted and report how the send went
+    fn finish_send(&mut self, outcome: Result<Vec<Result<(), String>>, String>) {
+        let sent = std::mem::take(&mut self.sending);
+        let outcomes = match outcome {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                self.send_failed = true;
+                self.sent_file_name = format!("Failed to send to Put.io: {}", e);
+                return;
+            }
+        };
+
+        // Tracked shows move past the episodes just sent
+        let mut failures = Vec::new();
+        let mut moved = false;
+        for (result, outcome) in sent.iter().zip(&outcomes) {
+            match outcome {
+                Ok(()) => {
+                    if let Some(hash) = result.infohash() {
+                        self.sent_log.record(&hash, &result.title);
+                    }
+                    moved |= self.series.record_sent(result);
+                }
+                Err(e) => failures.push(e),
+            }
+        }
+        if failures.len() < sent.len() {
+            if let Err(e) = self.sent_log.save() {
+                if self.debug {
+                    eprintln!("[DEBUG] Failed to save sent log: {}", e);
+                }
+            }
+        }
+        if moved {
+            self.save_series();
+        }
+
+        self.send_failed = !failures.is_empty();
+        self.sent_file_name = match (sent.as_slice(), failures.first()) {
+            ([result], None) => format!("Sent '{}' to Put.io!", result.title),
+            ([result], Some(e)) => format!("Failed to send '{}': {}", result.title, e),
+            (_, None) => format!("Sent {} files to Put.io!", sent.len()),
+            (_, Some(e)) => format!("Sent {} of {} files to Put.io, {} failed: {}",
+                sent.len() - failures.len(), sent.len(), failures.len(), e),
+        };
     }
 }
\ No newline at end of file
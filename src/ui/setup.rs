@@ -1,127 +1,787 @@
+use crossterm::{
+    cursor, event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue, style::{Color, Print, SetBackgroundColor, SetForegroundColor},
+    terminal::{self, ClearType},
+};
 use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use super::{AnimationConfig, DraculaTheme};
+use crate::api::{ChillClient, PutioClient};
 use crate::config::Config;
-use crate::api::PutioClient;
+use crate::models::PutioFile;
 
-pub fn run_setup_wizard() -> Result<Config, Box<dyn std::error::Error>> {
-    let mut config = Config::load().unwrap_or_default();
+/// Query used to check that a chill.institute API key is accepted
+const PROBE_QUERY: &str = "ubuntu";
+const WIZARD_WIDTH: u16 = 72;
 
-    println!("═══════════════════════════════════════════════════════════");
-    println!("            ChillTUI First-Time Setup");
-    println!("═══════════════════════════════════════════════════════════\n");
+type CheckSlot = Arc<Mutex<Option<Result<CheckResult, String>>>>;
 
-    // Chill.institute API key
-    if config.chill_api_key.is_none() {
-        println!("Step 1: Chill.institute API Key");
-        println!("Request an API key by emailing: chill-institute@proton.me");
-        println!("Or via X: x.com/chill_institute");
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Step {
+    ChillKey,
+    PutioToken,
+    Folder,
+}
+
+impl Step {
+    const ALL: [Step; 3] = [Step::ChillKey, Step::PutioToken, Step::Folder];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Step::ChillKey => "Chill API key",
+            Step::PutioToken => "Put.io token",
+            Step::Folder => "Download folder",
+        }
+    }
+}
+
+/// Result of a background API call made by the wizard
+enum CheckResult {
+    ChillKey(usize),
+    PutioAccount(String),
+    Folders(Vec<PutioFile>),
+    FolderCreated(PutioFile),
+}
+
+enum Mode {
+    /// In-app settings menu listing every step
+    Menu { cursor: usize },
+    /// Walking through `steps`
+    Steps,
+}
+
+enum Status {
+    None,
+    Checking(String),
+    Ok(String),
+    Error(String),
+}
 
+#[derive(Clone, Copy, PartialEq)]
+enum FolderRow {
+    UseCurrent,
+    NewFolder,
+    Up,
+    Folder(usize),
+}
+
+/// Browsable view of the user's Put.io folders
+struct FolderBrowser {
+    /// Folders from the root down to the one being viewed; the root is id 0
+    path: Vec<(u64, String)>,
+    entries: Vec<PutioFile>,
+    cursor: usize,
+    scroll: usize,
+    loaded: bool,
+    /// Name being typed for a new folder, if the user is creating one
+    naming: Option<String>,
+}
+
+impl FolderBrowser {
+    fn new() -> Self {
+        Self {
+            path: vec![(0, String::new())],
+            entries: Vec::new(),
+            cursor: 0,
+            scroll: 0,
+            loaded: false,
+            naming: None,
+        }
+    }
+
+    fn current_id(&self) -> u64 {
+        self.path.last().map(|(id, _)| *id).unwrap_or(0)
+    }
+
+    fn path_str(&self) -> String {
+        let names: Vec<&str> = self.path.iter().skip(1).map(|(_, name)| name.as_str()).collect();
+        if names.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}/", names.join("/"))
+        }
+    }
+
+    fn rows(&self) -> Vec<FolderRow> {
+        let mut rows = vec![FolderRow::UseCurrent, FolderRow::NewFolder];
+        if self.path.len() > 1 {
+            rows.push(FolderRow::Up);
+        }
+        rows.extend((0..self.entries.len()).map(FolderRow::Folder));
+        rows
+    }
+}
+
+pub struct SetupWizard {
+    config: Config,
+    mode: Mode,
+    steps: Vec<Step>,
+    step_index: usize,
+    input: String,
+    reveal: bool,
+    status: Status,
+    pending: CheckSlot,
+    checking: bool,
+    spinner_frame: u8,
+    folders: FolderBrowser,
+    /// Opened from the settings menu, so Esc on the first step returns there
+    from_menu: bool,
+}
+
+impl SetupWizard {
+    /// Wizard covering the given steps, in order
+    pub fn new(config: Config, steps: Vec<Step>) -> Self {
+        let mut wizard = Self {
+            config,
+            mode: Mode::Steps,
+            steps,
+            step_index: 0,
+            input: String::new(),
+            reveal: false,
+            status: Status::None,
+            pending: Arc::new(Mutex::new(None)),
+            checking: false,
+            spinner_frame: 0,
+            folders: FolderBrowser::new(),
+            from_menu: false,
+        };
+        wizard.enter_step();
+        wizard
+    }
+
+    /// Settings menu for changing a single value from inside the app
+    pub fn settings(config: Config) -> Self {
+        let mut wizard = Self::new(config, Vec::new());
+        wizard.mode = Mode::Menu { cursor: 0 };
+        wizard
+    }
+
+    /// Run the wizard on an already-initialised raw mode terminal.
+    ///
+    /// Returns the updated config once the last step is confirmed, or `None`
+    /// if the user backed out.
+    pub fn run(mut self) -> Result<Option<Config>, Box<dyn std::error::Error>> {
         loop {
-            print!("\nEnter your Chill API key: ");
-            io::stdout().flush()?;
+            self.poll_check();
+            self.draw()?;
+
+            if self.checking {
+                self.spinner_frame = self.spinner_frame.wrapping_add(1);
+            }
+
+            if event::poll(std::time::Duration::from_millis(AnimationConfig::FRAME_INTERVAL_MS))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                        return Ok(None);
+                    }
+                    match self.handle_key(key) {
+                        KeyResult::Continue => {}
+                        KeyResult::Cancel => return Ok(None),
+                        KeyResult::Finish => return Ok(Some(self.config)),
+                    }
+                }
+            }
+        }
+    }
+
+    fn current_step(&self) -> Option<Step> {
+        match self.mode {
+            Mode::Menu { .. } => None,
+            Mode::Steps => self.steps.get(self.step_index).copied(),
+        }
+    }
+
+    /// Reset per-step state and prefill the input with the current value
+    fn enter_step(&mut self) {
+        self.status = Status::None;
+        self.reveal = false;
+        self.input = match self.current_step() {
+            Some(Step::ChillKey) => self.config.chill_api_key.clone().unwrap_or_default(),
+            Some(Step::PutioToken) => self.config.putio_oauth_token.clone().unwrap_or_default(),
+            _ => String::new(),
+        };
+
+        if self.current_step() == Some(Step::Folder) {
+            self.folders = FolderBrowser::new();
+            self.load_folders();
+        }
+    }
 
-            let mut api_key = String::new();
-            io::stdin().read_line(&mut api_key)?;
-            let api_key = api_key.trim();
+    fn advance(&mut self) {
+        self.step_index += 1;
+        if self.step_index < self.steps.len() {
+            self.enter_step();
+        }
+    }
 
-            if api_key.is_empty() {
-                println!("✗ API key cannot be empty. Please try again.");
-                continue;
+    fn spawn_check<F>(&mut self, message: &str, check: F)
+    where
+        F: FnOnce() -> Result<CheckResult, String> + Send + 'static,
+    {
+        self.checking = true;
+        self.spinner_frame = 0;
+        self.status = Status::Checking(message.to_string());
+
+        let slot = Arc::clone(&self.pending);
+        thread::spawn(move || {
+            let result = check();
+            if let Ok(mut guard) = slot.lock() {
+                *guard = Some(result);
             }
+        });
+    }
 
-            if api_key.len() < 10 {
-                println!("✗ API key seems too short. Please check and try again.");
-                continue;
+    fn putio_client(&self) -> Option<PutioClient> {
+        self.config.putio_oauth_token.clone().map(PutioClient::new)
+    }
+
+    fn load_folders(&mut self) {
+        let Some(client) = self.putio_client() else {
+            self.status = Status::Error("Put.io token not configured".to_string());
+            return;
+        };
+        let parent_id = self.folders.current_id();
+        self.folders.loaded = false;
+        self.spawn_check("Loading folders", move || {
+            client.list_folders(parent_id)
+                .map(CheckResult::Folders)
+                .map_err(|e| e.to_string())
+        });
+    }
+
+    fn poll_check(&mut self) {
+        if !self.checking {
+            return;
+        }
+
+        let result = match self.pending.try_lock() {
+            Ok(mut guard) => guard.take(),
+            Err(_) => None,
+        };
+        let Some(result) = result else {
+            return;
+        };
+        self.checking = false;
+
+        match result {
+            Ok(CheckResult::ChillKey(count)) => {
+                self.config.chill_api_key = Some(self.input.trim().to_string());
+                self.status = Status::Ok(format!("API key accepted ({} probe results)", count));
+                self.finish_step();
+            }
+            Ok(CheckResult::PutioAccount(username)) => {
+                self.config.putio_oauth_token = Some(self.input.trim().to_string());
+                self.status = Status::Ok(format!("Connected as: {}", username));
+                self.finish_step();
+            }
+            Ok(CheckResult::Folders(mut folders)) => {
+                folders.sort_by_key(|f| f.name.to_lowercase());
+                self.folders.entries = folders;
+                self.folders.loaded = true;
+                self.folders.cursor = 0;
+                self.folders.scroll = 0;
+                self.status = Status::None;
             }
+            Ok(CheckResult::FolderCreated(folder)) => {
+                self.status = Status::Ok(format!("Created /{}/", folder.name));
+                self.folders.path.push((folder.id, folder.name));
+                self.load_folders();
+            }
+            Err(e) => {
+                self.status = Status::Error(e);
+            }
+        }
+    }
 
-            config.chill_api_key = Some(api_key.to_string());
-            println!("✓ API key accepted");
-            println!();
-            break;
+    /// Called once the current step's value has been verified
+    fn finish_step(&mut self) {
+        // Keep the success message visible on the next step unless that
+        // step immediately starts its own check
+        let status = std::mem::replace(&mut self.status, Status::None);
+        self.advance();
+        if matches!(self.status, Status::None) {
+            self.status = status;
         }
     }
 
-    // Put.io OAuth setup
-    if config.putio_oauth_token.is_none() {
-        println!("Step 2: Put.io Authentication");
-        println!("1. Go to: https://app.put.io/oauth");
-        println!("2. Click 'Create App' and fill in:");
-        println!("   - Name: ChillTUI (or any name)");
-        println!("   - Description: Personal torrent client");
-        println!("   - Website: http://localhost");
-        println!("   - Callback URL: http://localhost");
-        println!("3. After saving, click the key icon (🔑) next to your app");
-        println!("4. Copy the OAuth Token");
+    fn handle_key(&mut self, key: KeyEvent) -> KeyResult {
+        if self.checking {
+            // Ignore input while a check is running; Esc abandons the check
+            // and a fresh slot makes sure its late result is dropped
+            if key.code == KeyCode::Esc {
+                self.checking = false;
+                self.pending = Arc::new(Mutex::new(None));
+                self.status = Status::Error("Cancelled".to_string());
+            }
+            return KeyResult::Continue;
+        }
 
-        loop {
-            print!("\nEnter your Put.io OAuth token: ");
-            io::stdout().flush()?;
+        // All steps verified: wait for a final confirmation
+        if matches!(self.mode, Mode::Steps) && self.step_index >= self.steps.len() {
+            return match key.code {
+                KeyCode::Enter => KeyResult::Finish,
+                KeyCode::Esc => KeyResult::Cancel,
+                _ => KeyResult::Continue,
+            };
+        }
+
+        match self.mode {
+            Mode::Menu { cursor } => self.handle_menu_key(key, cursor),
+            Mode::Steps => match self.current_step() {
+                Some(Step::Folder) => self.handle_folder_key(key),
+                Some(step) => self.handle_secret_key(key, step),
+                None => KeyResult::Finish,
+            },
+        }
+    }
+
+    fn handle_menu_key(&mut self, key: KeyEvent, cursor: usize) -> KeyResult {
+        match key.code {
+            KeyCode::Up if cursor > 0 => self.mode = Mode::Menu { cursor: cursor - 1 },
+            KeyCode::Down if cursor + 1 < Step::ALL.len() => self.mode = Mode::Menu { cursor: cursor + 1 },
+            KeyCode::Enter => {
+                self.mode = Mode::Steps;
+                self.from_menu = true;
+                self.steps = vec![Step::ALL[cursor]];
+                self.step_index = 0;
+                self.enter_step();
+            }
+            KeyCode::Esc => return KeyResult::Cancel,
+            _ => {}
+        }
+        KeyResult::Continue
+    }
+
+    fn handle_secret_key(&mut self, key: KeyEvent, step: Step) -> KeyResult {
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.input.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Tab => {
+                self.reveal = !self.reveal;
+            }
+            KeyCode::Enter => self.submit_secret(step),
+            KeyCode::Esc => return self.back(),
+            _ => {}
+        }
+        KeyResult::Continue
+    }
+
+    fn submit_secret(&mut self, step: Step) {
+        let value = self.input.trim().to_string();
+
+        match step {
+            Step::ChillKey => {
+                if value.is_empty() {
+                    self.status = Status::Error("API key cannot be empty".to_string());
+                } else if value.len() < 10 {
+                    self.status = Status::Error("API key seems too short. Please check and try again.".to_string());
+                } else {
+                    let client = ChillClient::new(value, self.config.putio_oauth_token.clone());
+                    self.spawn_check("Checking API key with a probe search", move || {
+                        client.search(PROBE_QUERY, None, true)
+                            .map(|results| CheckResult::ChillKey(results.len()))
+                            .map_err(|e| format!("Chill API rejected the key: {}", e))
+                    });
+                }
+            }
+            Step::PutioToken => {
+                if value.is_empty() {
+                    self.status = Status::Error("OAuth token cannot be empty".to_string());
+                } else if value.len() < 20 {
+                    self.status = Status::Error("OAuth token seems too short. Please check and try again.".to_string());
+                } else {
+                    let client = PutioClient::new(value);
+                    self.spawn_check("Connecting to Put.io", move || {
+                        client.test_connection()
+                            .map(CheckResult::PutioAccount)
+                            .map_err(|e| format!("Failed to connect to Put.io: {}", e))
+                    });
+                }
+            }
+            Step::Folder => {}
+        }
+    }
+
+    fn handle_folder_key(&mut self, key: KeyEvent) -> KeyResult {
+        if let Some(name) = self.folders.naming.as_mut() {
+            match key.code {
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => name.push(c),
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Esc => self.folders.naming = None,
+                KeyCode::Enter => {
+                    let name = name.trim().to_string();
+                    self.folders.naming = None;
+                    if name.is_empty() {
+                        self.status = Status::Error("Folder name cannot be empty".to_string());
+                    } else if let Some(client) = self.putio_client() {
+                        let parent_id = self.folders.current_id();
+                        self.spawn_check("Creating folder", move || {
+                            client.create_folder(&name, parent_id)
+                                .map(CheckResult::FolderCreated)
+                                .map_err(|e| format!("Failed to create folder: {}", e))
+                        });
+                    }
+                }
+                _ => {}
+            }
+            return KeyResult::Continue;
+        }
+
+        let rows = self.folders.rows();
+        match key.code {
+            KeyCode::Up if self.folders.cursor > 0 => self.folders.cursor -= 1,
+            KeyCode::Down if self.folders.cursor + 1 < rows.len() => self.folders.cursor += 1,
+            KeyCode::Backspace | KeyCode::Left if self.folders.path.len() > 1 => {
+                self.folders.path.pop();
+                self.load_folders();
+            }
+            KeyCode::Char('r') => self.load_folders(),
+            KeyCode::Enter | KeyCode::Right => {
+                match rows.get(self.folders.cursor).copied() {
+                    Some(FolderRow::UseCurrent) if key.code == KeyCode::Enter => {
+                        self.config.putio_folder_id = Some(self.folders.current_id());
+                        self.config.putio_folder_name = self.folders.path_str();
+                        self.status = Status::Ok(format!("Saving to {}", self.folders.path_str()));
+                        self.finish_step();
+                    }
+                    Some(FolderRow::NewFolder) if key.code == KeyCode::Enter => {
+                        self.folders.naming = Some(String::new());
+                        self.status = Status::None;
+                    }
+                    Some(FolderRow::Up) => {
+                        self.folders.path.pop();
+                        self.load_folders();
+                    }
+                    Some(FolderRow::Folder(i)) => {
+                        let folder = self.folders.entries[i].clone();
+                        self.folders.path.push((folder.id, folder.name));
+                        self.load_folders();
+                    }
+                    _ => {}
+                }
+            }
+            KeyCode::Esc => return self.back(),
+            _ => {}
+        }
+        KeyResult::Continue
+    }
+
+    /// Esc: go to the previous step, the settings menu, or cancel entirely
+    fn back(&mut self) -> KeyResult {
+        if self.step_index > 0 {
+            self.step_index -= 1;
+            self.enter_step();
+            return KeyResult::Continue;
+        }
+        if self.from_menu {
+            let cursor = self.steps.first()
+                .and_then(|step| Step::ALL.iter().position(|s| s == step))
+                .unwrap_or(0);
+            self.mode = Mode::Menu { cursor };
+            self.status = Status::None;
+            return KeyResult::Continue;
+        }
+        KeyResult::Cancel
+    }
+
+    fn draw(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut stdout = io::stdout();
+        let (term_width, term_height) = terminal::size()?;
+
+        let width = WIZARD_WIDTH.min(term_width.saturating_sub(4)).max(30);
+        let inner = width as usize - 4;
+        let x = term_width.saturating_sub(width) / 2;
+
+        let lines = self.body_lines(inner, term_height);
+        let height = lines.len() as u16 + 6;
+        let y = term_height.saturating_sub(height) / 2;
+
+        queue!(
+            stdout,
+            SetBackgroundColor(DraculaTheme::BG),
+            terminal::Clear(ClearType::All),
+        )?;
+
+        // Title: "┌─ CHILLTUI SETUP ───┐"
+        let title = match self.mode {
+            Mode::Menu { .. } => "┌─ CHILLTUI SETTINGS ",
+            Mode::Steps => "┌─ CHILLTUI SETUP ",
+        };
+        queue!(
+            stdout,
+            cursor::MoveTo(x, y),
+            SetForegroundColor(DraculaTheme::CYAN),
+            Print(title),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print("─".repeat((width as usize).saturating_sub(title.chars().count() + 1))),
+            Print("┐"),
+        )?;
+
+        // Step indicator
+        queue!(stdout, cursor::MoveTo(x, y + 1), SetForegroundColor(DraculaTheme::FG_DIM), Print("│ "))?;
+        let mut used = 0;
+        if matches!(self.mode, Mode::Steps) && self.steps.len() > 1 {
+            for (i, step) in self.steps.iter().enumerate() {
+                let label = format!(" {} {} ", i + 1, step.label());
+                let (fg, bg) = if i == self.step_index {
+                    (DraculaTheme::BG, DraculaTheme::PINK)
+                } else if i < self.step_index {
+                    (DraculaTheme::GREEN, DraculaTheme::BG)
+                } else {
+                    (DraculaTheme::FG_DIM, DraculaTheme::BG)
+                };
+                queue!(stdout, SetBackgroundColor(bg), SetForegroundColor(fg), Print(&label), SetBackgroundColor(DraculaTheme::BG))?;
+                used += label.chars().count();
+            }
+        } else if let Some(step) = self.current_step() {
+            let label = format!(" {} ", step.label());
+            queue!(stdout, SetBackgroundColor(DraculaTheme::PINK), SetForegroundColor(DraculaTheme::BG), Print(&label), SetBackgroundColor(DraculaTheme::BG))?;
+            used += label.chars().count();
+        }
+        queue!(
+            stdout,
+            Print(" ".repeat(inner.saturating_sub(used))),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print(" │"),
+            cursor::MoveTo(x, y + 2),
+            Print("│"),
+            Print(" ".repeat(width as usize - 2)),
+            Print("│"),
+        )?;
+
+        // Body
+        for (i, (text, fg, bg)) in lines.iter().enumerate() {
+            let text: String = text.chars().take(inner).collect();
+            let pad = inner.saturating_sub(text.chars().count());
+            queue!(
+                stdout,
+                cursor::MoveTo(x, y + 3 + i as u16),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print("│ "),
+                SetBackgroundColor(*bg),
+                SetForegroundColor(*fg),
+                Print(&text),
+                Print(" ".repeat(pad)),
+                SetBackgroundColor(DraculaTheme::BG),
+                SetForegroundColor(DraculaTheme::FG_DIM),
+                Print(" │"),
+            )?;
+        }
 
-            let mut token = String::new();
-            io::stdin().read_line(&mut token)?;
-            let token = token.trim();
+        // Status line
+        let status_y = y + 3 + lines.len() as u16;
+        let spinner_chars = ['|', '/', '-', '\\'];
+        let (status, color) = match &self.status {
+            Status::None => (String::new(), DraculaTheme::FG),
+            Status::Checking(msg) => (format!("{} {}", spinner_chars[self.spinner_frame as usize % 4], msg), DraculaTheme::CYAN),
+            Status::Ok(msg) => (format!("✓ {}", msg), DraculaTheme::GREEN),
+            Status::Error(msg) => (format!("✗ {}", msg), DraculaTheme::RED),
+        };
+        let status: String = status.chars().take(inner).collect();
+        queue!(
+            stdout,
+            cursor::MoveTo(x, status_y),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print("│ "),
+            SetForegroundColor(color),
+            Print(&status),
+            Print(" ".repeat(inner.saturating_sub(status.chars().count()))),
+            SetForegroundColor(DraculaTheme::FG_DIM),
+            Print(" │"),
+            cursor::MoveTo(x, status_y + 1),
+            Print("└"),
+            Print("─".repeat(width as usize - 2)),
+            Print("┘"),
+        )?;
 
-            if token.is_empty() {
-                println!("✗ OAuth token cannot be empty. Please try again.");
-                continue;
+        // Key help under the box
+        let help = self.help_text();
+        queue!(
+            stdout,
+            cursor::MoveTo(x, status_y + 2),
+            SetForegroundColor(DraculaTheme::CYAN),
+            Print(help.chars().take(width as usize).collect::<String>()),
+        )?;
+
+        stdout.flush()?;
+        Ok(())
+    }
+
+    fn help_text(&self) -> &'static str {
+        if matches!(self.mode, Mode::Steps) && self.step_index >= self.steps.len() {
+            return "Enter: save and continue | ESC: cancel";
+        }
+        match (&self.mode, self.current_step()) {
+            (Mode::Menu { .. }, _) => "↑↓: choose setting | Enter: edit | ESC: back to app",
+            (_, Some(Step::Folder)) if self.folders.naming.is_some() => "Type a name | Enter: create | ESC: cancel",
+            (_, Some(Step::Folder)) => "↑↓: navigate | Enter/→: open | ←: up | r: reload | ESC: back",
+            _ => "Type or paste | Enter: verify | Tab: show/hide | ESC: back",
+        }
+    }
+
+    /// Text, foreground and background of each body row
+    fn body_lines(&mut self, inner: usize, term_height: u16) -> Vec<(String, Color, Color)> {
+        let plain = |text: &str| (text.to_string(), DraculaTheme::FG_DIM, DraculaTheme::BG);
+        let mut lines = Vec::new();
+
+        if let Mode::Menu { cursor } = self.mode {
+            lines.push(plain("Choose a setting to change:"));
+            lines.push(plain(""));
+            for (i, step) in Step::ALL.iter().enumerate() {
+                let value = match step {
+                    Step::ChillKey => mask(self.config.chill_api_key.as_deref().unwrap_or("not set")),
+                    Step::PutioToken => mask(self.config.putio_oauth_token.as_deref().unwrap_or("not set")),
+                    Step::Folder => self.config.folder_label(),
+                };
+                let text = format!("{:<18}{}", step.label(), value);
+                if i == cursor {
+                    lines.push((format!("▶ {}", text), DraculaTheme::BG, DraculaTheme::PINK));
+                } else {
+                    lines.push((format!("  {}", text), DraculaTheme::FG, DraculaTheme::BG));
+                }
             }
+            return lines;
+        }
 
-            if token.len() < 20 {
-                println!("✗ OAuth token seems too short. Please check and try again.");
-                continue;
+        let Some(step) = self.current_step() else {
+            lines.push(("All settings verified.".to_string(), DraculaTheme::GREEN, DraculaTheme::BG));
+            lines.push(plain(""));
+            lines.push(plain(&format!("Downloads will be saved to {} on Put.io.", self.config.folder_label())));
+            lines.push(plain("Press Enter to save the configuration."));
+            return lines;
+        };
+
+        match step {
+            Step::ChillKey => {
+                lines.push(plain("Request an API key by emailing: chill-institute@proton.me"));
+                lines.push(plain("Or via X: x.com/chill_institute"));
+                lines.push(plain(""));
+                lines.push(self.input_line("API key: ", inner));
+            }
+            Step::PutioToken => {
+                lines.push(plain("1. Go to: https://app.put.io/oauth"));
+                lines.push(plain("2. Click 'Create App' (any name, website and callback"));
+                lines.push(plain("   http://localhost)"));
+                lines.push(plain("3. Click the key icon (🔑) next to your app"));
+                lines.push(plain("4. Copy the OAuth Token and paste it below"));
+                lines.push(plain(""));
+                lines.push(self.input_line("OAuth token: ", inner));
             }
+            Step::Folder => {
+                lines.push(plain("Choose where to save downloads on Put.io:"));
+                lines.push((format!("Current: {}", self.folders.path_str()), DraculaTheme::CYAN, DraculaTheme::BG));
+                lines.push(plain(""));
 
-            // Test connection
-            let client = PutioClient::new(token.to_string());
-            match client.test_connection() {
-                Ok(username) => {
-                    config.putio_oauth_token = Some(token.to_string());
-                    println!("✓ Connected as: {}\n", username);
-                    break;
+                if let Some(name) = &self.folders.naming {
+                    lines.push((format!("New folder name: {}_", name), DraculaTheme::YELLOW, DraculaTheme::BG));
+                    return lines;
                 }
-                Err(e) => {
-                    println!("✗ Failed to connect to Put.io: {}", e);
-                    println!("  Please check your token and try again.");
-                    continue;
+
+                let rows = self.folders.rows();
+                let visible = (term_height as usize).saturating_sub(16).clamp(3, 15);
+                if self.folders.cursor < self.folders.scroll {
+                    self.folders.scroll = self.folders.cursor;
+                } else if self.folders.cursor >= self.folders.scroll + visible {
+                    self.folders.scroll = self.folders.cursor + 1 - visible;
+                }
+
+                for (i, row) in rows.iter().enumerate().skip(self.folders.scroll).take(visible) {
+                    let text = match row {
+                        FolderRow::UseCurrent => format!("✓ Use {}", self.folders.path_str()),
+                        FolderRow::NewFolder => "+ New folder here".to_string(),
+                        FolderRow::Up => "↑ ..".to_string(),
+                        FolderRow::Folder(idx) => format!("  {}/", self.folders.entries[*idx].name),
+                    };
+                    if i == self.folders.cursor {
+                        lines.push((text, DraculaTheme::BG, DraculaTheme::PINK));
+                    } else {
+                        lines.push((text, DraculaTheme::FG, DraculaTheme::BG));
+                    }
+                }
+
+                if self.folders.loaded && self.folders.entries.is_empty() {
+                    lines.push(plain("  (no subfolders)"));
                 }
             }
         }
+
+        lines
     }
 
-    // Folder setup
-    if config.putio_folder_id.is_none() {
-        println!("Step 3: Put.io Folder Setup");
-        println!("Choose where to save downloads on Put.io:");
-        println!("  1. Use default folder: /ChillTUI/");
-        println!("  2. Create custom folder");
-        print!("\nChoice [1]: ");
-        io::stdout().flush()?;
-
-        let mut choice = String::new();
-        io::stdin().read_line(&mut choice)?;
-        let choice = choice.trim();
-
-        if choice == "2" {
-            print!("Enter folder name: ");
-            io::stdout().flush()?;
-            let mut folder_name = String::new();
-            io::stdin().read_line(&mut folder_name)?;
-            config.putio_folder_name = folder_name.trim().to_string();
+    fn input_line(&self, label: &str, inner: usize) -> (String, Color, Color) {
+        let shown = if self.reveal {
+            self.input.clone()
         } else {
-            config.putio_folder_name = "ChillTUI".to_string();
-        }
+            "•".repeat(self.input.chars().count())
+        };
+
+        // Keep the end of long values visible
+        let max = inner.saturating_sub(label.chars().count() + 3);
+        let count = shown.chars().count();
+        let shown: String = shown.chars().skip(count.saturating_sub(max)).collect();
+
+        (format!("▶ {}{}_", label, shown), DraculaTheme::CYAN, DraculaTheme::BG)
+    }
+}
+
+enum KeyResult {
+    Continue,
+    Cancel,
+    Finish,
+}
+
+/// Show only the last few characters of a secret
+fn mask(secret: &str) -> String {
+    let count = secret.chars().count();
+    if count <= 4 || secret == "not set" {
+        return secret.to_string();
+    }
+    let tail: String = secret.chars().skip(count - 4).collect();
+    format!("{}{}", "•".repeat(8), tail)
+}
+
+/// First-run (or `--setup`) wizard. Runs full screen and saves the config.
+pub fn run_setup_wizard() -> Result<Config, Box<dyn std::error::Error>> {
+    // A config that can't be read is an error, not a blank slate: saving
+    // defaults over it would lose everything but the credentials
+    let config = Config::load()?;
 
-        // Create folder
-        let client = PutioClient::new(config.putio_oauth_token.as_ref().unwrap().clone());
-        let folder_id = client.find_or_create_folder(&config.putio_folder_name)?;
-        config.putio_folder_id = Some(folder_id);
-        println!("✓ Folder created: /{}/\n", config.putio_folder_name);
+    // Only ask for what's missing, unless everything is set and the user
+    // explicitly asked for setup
+    let mut steps: Vec<Step> = Vec::new();
+    if config.chill_api_key.is_none() {
+        steps.push(Step::ChillKey);
+    }
+    if config.putio_oauth_token.is_none() {
+        steps.push(Step::PutioToken);
+    }
+    if config.putio_folder_id.is_none() {
+        steps.push(Step::Folder);
     }
+    if steps.is_empty() {
+        steps = Step::ALL.to_vec();
+    }
+
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = SetupWizard::new(config, steps).run();
+
+    execute!(stdout, terminal::LeaveAlternateScreen, cursor::Show)?;
+    terminal::disable_raw_mode()?;
 
-    // Save config
+    let config = result?.ok_or("Setup cancelled")?;
     config.save()?;
-    println!("═══════════════════════════════════════════════════════════");
     println!("✓ Setup complete! Configuration saved.");
-    println!("═══════════════════════════════════════════════════════════\n");
 
     Ok(config)
-}
\ No newline at end of file
+}